@@ -34,7 +34,7 @@
 
 use std::{
     borrow::Cow,
-    cmp::max,
+    cmp::{max, Ordering},
     collections::HashMap,
     fmt::{self, Display},
     iter,
@@ -44,8 +44,11 @@ use std::{
 pub struct Grid {
     size: (usize, usize),
     border_styles: Vec<Border>,
-    styles: HashMap<Entity, Style>,
+    styles: HashMap<Entity, EntityStyle>,
+    colors: HashMap<Entity, Color>,
     cells: Vec<Vec<String>>,
+    joints: HashMap<(usize, usize, usize), (char, char)>,
+    footer: usize,
 }
 
 impl Grid {
@@ -70,7 +73,7 @@ impl Grid {
     /// ```
     pub fn new(rows: usize, columns: usize) -> Self {
         let mut styles = HashMap::new();
-        styles.insert(Entity::Global, Style::default());
+        styles.insert(Entity::Global, EntityStyle::from(Style::default()));
 
         let border_styles = iter::repeat(Self::default_border()).take(rows).collect();
 
@@ -79,6 +82,9 @@ impl Grid {
             cells: vec![vec![String::new(); columns]; rows],
             border_styles,
             styles,
+            colors: HashMap::new(),
+            joints: HashMap::new(),
+            footer: 0,
         }
     }
 
@@ -108,6 +114,11 @@ impl Grid {
             self.set_text(&entity, text);
         }
 
+        // A color is kept apart from a style so it doesn't reset ident and alignment
+        if let Some(color) = settings.color {
+            self.colors.insert(entity.clone(), color);
+        }
+
         if settings.ident.is_none() && settings.alignment.is_none() {
             return;
         }
@@ -115,6 +126,8 @@ impl Grid {
         // todo: might it's worth to be able to modify only ident/alignemt of a cell instead of the whole style
         // an example when we have a global setting with ident and we only want to modify an alignment of a partical cell
         // but Style::default() will override global ident...
+        //
+        // `Grid::set_alignment` does so for an alignment.
         let mut s = Style::default();
         if let Some(ident) = settings.ident {
             s.ident = ident;
@@ -123,11 +136,38 @@ impl Grid {
             s.alignment = alignment;
         }
 
-        self.styles.insert(entity, s);
+        self.styles.insert(entity, s.into());
+    }
+
+    /// Set_alignment changes only an alignment of an `entity`.
+    ///
+    /// Unlike [`Grid::set`] it keeps an ident, so if it's not set for the entity
+    /// it's taken from a column, a row or a global setting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, Alignment};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a").ident(1, 1, 0, 0));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///     grid.set_alignment(Entity::Cell(1, 0), Alignment::Right);
+    ///     let str = grid.to_string();
+    ///     assert_eq!(
+    ///          str,
+    ///          "+-----+\n\
+    ///           | asd |\n\
+    ///           +-----+\n\
+    ///           |   a |\n\
+    ///           +-----+\n"
+    ///     )
+    /// ```
+    pub fn set_alignment(&mut self, entity: Entity, alignment: Alignment) {
+        self.styles.entry(entity).or_default().alignment = Some(alignment);
     }
 
     /// get_cell_content returns content without any style changes
-    pub fn get_cell_content(&mut self, row: usize, column: usize) -> &str {
+    pub fn get_cell_content(&self, row: usize, column: usize) -> &str {
         self.cells[row][column].as_str()
     }
 
@@ -140,6 +180,19 @@ impl Grid {
         self.size.1
     }
 
+    /// Set_footer marks `count` last rows of a grid as a footer.
+    ///
+    /// The mark is kept while rows are inserted or removed,
+    /// so options like sorting can leave the footer rows in place.
+    pub fn set_footer(&mut self, count: usize) {
+        self.footer = count.min(self.count_rows());
+    }
+
+    /// Count_footer_rows returns an amount of last rows which are marked as a footer
+    pub fn count_footer_rows(&self) -> usize {
+        self.footer
+    }
+
     /// Get_border_mut returns a border for a given row.
     /// The border can be modified.
     ///
@@ -173,16 +226,145 @@ impl Grid {
         &mut self.border_styles[row]
     }
 
+    /// Set_joint replaces vertical borders around a `line` of a cell by `left` and `right` characters.
+    ///
+    /// It lets a content of a cell, like a nested table, join borders of the grid.
+    /// A joined line is filled by its last character up to a width of a column rather than by spaces.
+    ///
+    /// Joints are drawn only in place of existing borders,
+    /// so a grid without a vertical border keeps it not drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a\n-\nb"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("c"));
+    ///     grid.set_joint(0, 0, 1, '+', '+');
+    ///     let str = grid.to_string();
+    ///     assert_eq!(
+    ///          str,
+    ///          "+-+-+\n\
+    ///           |a|c|\n\
+    ///           +-+ |\n\
+    ///           |b| |\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn set_joint(&mut self, row: usize, column: usize, line: usize, left: char, right: char) {
+        self.joints.insert((row, column, line), (left, right));
+    }
+
+    /// Insert_row inserts an empty `row` into a grid.
+    ///
+    /// The row gets a default border.
+    /// Styles of following rows are shifted together with them.
+    /// A row inserted after the first footer row becomes a part of the footer.
+    ///
+    /// The row index must be started from 0 and be no greater than an amount of rows.
+    pub fn insert_row(&mut self, row: usize) {
+        if self.footer > 0 && row > self.count_rows() - self.footer {
+            self.footer += 1;
+        }
+
+        self.cells
+            .insert(row, vec![String::new(); self.count_columns()]);
+        self.border_styles.insert(row, Self::default_border());
+        self.size.0 += 1;
+
+        shift_rows(&mut self.styles, row);
+        shift_rows(&mut self.colors, row);
+
+        self.joints = self
+            .joints
+            .drain()
+            .map(|((r, c, line), joint)| {
+                if r >= row {
+                    ((r + 1, c, line), joint)
+                } else {
+                    ((r, c, line), joint)
+                }
+            })
+            .collect();
+    }
+
+    /// Insert_column inserts an empty `column` into a grid.
+    ///
+    /// Styles and inner splits of following columns are shifted together with them.
+    ///
+    /// The column index must be started from 0 and be no greater than an amount of columns.
+    pub fn insert_column(&mut self, column: usize) {
+        for row in &mut self.cells {
+            row.insert(column, String::new());
+        }
+        self.size.1 += 1;
+
+        for border in &mut self.border_styles {
+            border.inner_splits = border
+                .inner_splits
+                .drain()
+                .map(|(c, split)| {
+                    if c >= column {
+                        (c + 1, split)
+                    } else {
+                        (c, split)
+                    }
+                })
+                .collect();
+        }
+
+        shift_columns(&mut self.styles, column);
+        shift_columns(&mut self.colors, column);
+
+        self.joints = self
+            .joints
+            .drain()
+            .map(|((r, c, line), joint)| {
+                if c >= column {
+                    ((r, c + 1, line), joint)
+                } else {
+                    ((r, c, line), joint)
+                }
+            })
+            .collect();
+    }
+
     /// Remove_row removes a `row` from a grid.
     ///
+    /// Styles of the row are dropped and styles of following rows are shifted together with them.
+    ///
     /// The row index must be started from 0
     pub fn remove_row(&mut self, row: usize) {
+        if row >= self.count_rows() - self.footer {
+            self.footer -= 1;
+        }
+
         self.cells.remove(row);
         self.border_styles.remove(row);
         self.size.0 -= 1;
+
+        drop_row(&mut self.styles, row);
+        drop_row(&mut self.colors, row);
+
+        self.joints = self
+            .joints
+            .drain()
+            .filter(|((r, _, _), _)| *r != row)
+            .map(|((r, c, line), joint)| {
+                if r > row {
+                    ((r - 1, c, line), joint)
+                } else {
+                    ((r, c, line), joint)
+                }
+            })
+            .collect();
     }
 
-    /// Remove_row removes a `column` from a grid.
+    /// Remove_column removes a `column` from a grid.
+    ///
+    /// Styles and inner splits of the column are dropped
+    /// and the ones of following columns are shifted together with them.
     ///
     /// The column index must be started from 0
     pub fn remove_column(&mut self, column: usize) {
@@ -190,6 +372,166 @@ impl Grid {
         for row in 0..self.count_rows() {
             self.cells[row].remove(column);
         }
+
+        for border in &mut self.border_styles {
+            border.inner_splits = border
+                .inner_splits
+                .drain()
+                .filter(|(c, _)| *c != column)
+                .map(|(c, split)| {
+                    if c > column {
+                        (c - 1, split)
+                    } else {
+                        (c, split)
+                    }
+                })
+                .collect();
+        }
+
+        drop_column(&mut self.styles, column);
+        drop_column(&mut self.colors, column);
+
+        self.joints = self
+            .joints
+            .drain()
+            .filter(|((_, c, _), _)| *c != column)
+            .map(|((r, c, line), joint)| {
+                if c > column {
+                    ((r, c - 1, line), joint)
+                } else {
+                    ((r, c, line), joint)
+                }
+            })
+            .collect();
+    }
+
+    /// Swap_rows exchanges content and styles of 2 rows.
+    ///
+    /// Borders are not moved as they are bound to a position rather than a content.
+    pub fn swap_rows(&mut self, lhs: usize, rhs: usize) {
+        if lhs == rhs {
+            return;
+        }
+
+        self.cells.swap(lhs, rhs);
+
+        swap_styles(&mut self.styles, Entity::Row(lhs), Entity::Row(rhs));
+        swap_styles(&mut self.colors, Entity::Row(lhs), Entity::Row(rhs));
+        for column in 0..self.count_columns() {
+            swap_styles(
+                &mut self.styles,
+                Entity::Cell(lhs, column),
+                Entity::Cell(rhs, column),
+            );
+            swap_styles(
+                &mut self.colors,
+                Entity::Cell(lhs, column),
+                Entity::Cell(rhs, column),
+            );
+        }
+
+        self.joints = self
+            .joints
+            .drain()
+            .map(|((r, c, line), joint)| match r {
+                r if r == lhs => ((rhs, c, line), joint),
+                r if r == rhs => ((lhs, c, line), joint),
+                r => ((r, c, line), joint),
+            })
+            .collect();
+    }
+
+    /// Concat_horizontal appends columns of `other` grid to the right side of the grid.
+    ///
+    /// A grid with less rows is padded with empty cells.
+    /// Cells of `other` grid keep their styles, and rows which exist in both grids keep borders of this one.
+    pub fn concat_horizontal(&mut self, other: &Grid) {
+        // the footer of a grid with more rows is the one which stays at the bottom
+        let footer = match self.count_rows().cmp(&other.count_rows()) {
+            Ordering::Less => other.footer,
+            Ordering::Equal => max(self.footer, other.footer),
+            Ordering::Greater => self.footer,
+        };
+
+        let offset = self.count_columns();
+        while self.count_rows() < other.count_rows() {
+            let row = self.count_rows();
+            self.insert_row(row);
+            self.border_styles[row] = other.border_styles[row].clone();
+            self.border_styles[row].inner_splits.clear();
+        }
+
+        for (row, cells) in self.cells.iter_mut().enumerate() {
+            match other.cells.get(row) {
+                Some(other_cells) => cells.extend(other_cells.iter().cloned()),
+                None => cells.resize(offset + other.count_columns(), String::new()),
+            }
+        }
+        self.size.1 += other.count_columns();
+
+        for (row, border) in other.border_styles.iter().enumerate() {
+            let splits = &mut self.border_styles[row].inner_splits;
+            for (column, split) in &border.inner_splits {
+                splits.insert(offset + column, *split);
+            }
+        }
+
+        for row in 0..other.count_rows() {
+            for column in 0..other.count_columns() {
+                let style = other.style(row, column);
+                self.styles
+                    .insert(Entity::Cell(row, offset + column), style.into());
+
+                if let Some(color) = other.color(row, column) {
+                    self.colors
+                        .insert(Entity::Cell(row, offset + column), color.clone());
+                }
+            }
+        }
+
+        for (&(row, column, line), &joint) in &other.joints {
+            self.joints.insert((row, offset + column, line), joint);
+        }
+
+        self.footer = footer;
+    }
+
+    /// Concat_vertical appends rows of `other` grid to the bottom of the grid.
+    ///
+    /// A grid with less columns is padded with empty cells.
+    /// Cells and borders of `other` grid keep their styles.
+    pub fn concat_vertical(&mut self, other: &Grid) {
+        let offset = self.count_rows();
+        let count_columns = max(self.count_columns(), other.count_columns());
+        for cells in &mut self.cells {
+            cells.resize(count_columns, String::new());
+        }
+        self.size.1 = count_columns;
+
+        for (row, cells) in other.cells.iter().enumerate() {
+            let mut cells = cells.clone();
+            cells.resize(count_columns, String::new());
+            self.cells.push(cells);
+            self.border_styles.push(other.border_styles[row].clone());
+
+            for column in 0..other.count_columns() {
+                let style = other.style(row, column);
+                self.styles
+                    .insert(Entity::Cell(offset + row, column), style.into());
+
+                if let Some(color) = other.color(row, column) {
+                    self.colors
+                        .insert(Entity::Cell(offset + row, column), color.clone());
+                }
+            }
+        }
+        self.size.0 += other.count_rows();
+
+        for (&(row, column, line), &joint) in &other.joints {
+            self.joints.insert((offset + row, column, line), joint);
+        }
+
+        self.footer = other.footer;
     }
 
     fn columns_width(&self) -> Vec<usize> {
@@ -264,13 +606,22 @@ impl Grid {
             self.styles.get(&Entity::Global),
         ];
 
-        for styles in &v {
-            if let Some(style) = styles {
-                return (*style).clone();
-            }
+        // each setting is taken from the most specific entity which has it
+        let ident = v.iter().flatten().find_map(|style| style.ident.clone());
+        let alignment = v.iter().flatten().find_map(|style| style.alignment.clone());
+
+        match (ident, alignment) {
+            (Some(ident), Some(alignment)) => Style { ident, alignment },
+            _ => unreachable!("there's a global settings guaranted in the map"),
         }
+    }
 
-        unreachable!("there's a global settings guaranted in the map")
+    fn color(&self, row: usize, column: usize) -> Option<&Color> {
+        self.colors
+            .get(&Entity::Cell(row, column))
+            .or_else(|| self.colors.get(&Entity::Column(column)))
+            .or_else(|| self.colors.get(&Entity::Row(row)))
+            .or_else(|| self.colors.get(&Entity::Global))
     }
 
     fn default_border() -> Border {
@@ -293,28 +644,36 @@ impl Grid {
                 left_intersection: Some('+'),
                 right_intersection: Some('+'),
             },
+            inner_splits: HashMap::new(),
         }
     }
 
-    fn build_cells<'a>(&'a self) -> Vec<Vec<Vec<String>>> {
+    fn build_cells(&self) -> Vec<Vec<Vec<String>>> {
         let columns_widths = self.columns_width();
         let rows_height = self.rows_height();
 
         let count_rows = self.count_rows();
         let count_columns = self.count_columns();
         let mut rows = Vec::with_capacity(count_rows);
-        for row in 0..count_rows {
+        for (row, height) in rows_height.iter().enumerate() {
             let mut cells = Vec::with_capacity(count_columns);
 
-            for column in 0..count_columns {
+            for (column, width) in columns_widths.iter().enumerate() {
                 let style = self.style(row, column);
 
-                let text = build_cell(
-                    &self.cells[row][column],
-                    style,
-                    columns_widths[column],
-                    rows_height[row],
-                );
+                let mut text = build_cell(&self.cells[row][column], style, *width, *height);
+                for (line, text) in text.iter_mut().enumerate() {
+                    if self.joints.contains_key(&(row, column, line)) {
+                        *text = fill_joined_line(text);
+                    }
+                }
+
+                // It's done after all width calculations so escape sequences don't affect them
+                if let Some(color) = self.color(row, column) {
+                    for text in text.iter_mut() {
+                        *text = color.paint(text);
+                    }
+                }
 
                 cells.push(text);
             }
@@ -328,22 +687,45 @@ impl Grid {
     fn build_row(
         &self,
         f: &mut std::fmt::Formatter<'_>,
+        index: usize,
         row: &[Vec<String>],
-        border: &LineStyle,
+        border: &Border,
     ) -> fmt::Result {
-        let height = row.iter().map(|cell| cell.len()).max().unwrap_or_else(|| 0);
+        let height = row.iter().map(|cell| cell.len()).max().unwrap_or(0);
         for i in 0..height {
-            write_option(f, border.left_intersection)?;
+            let joint = |column: usize| self.joints.get(&(index, column, i)).copied();
+
+            // joints only replace borders which are drawn
+            let left = border
+                .inner
+                .left_intersection
+                .map(|c| joint(0).map_or(c, |(left, _)| left));
+            write_option(f, left)?;
 
             for (y, cell) in row.iter().enumerate() {
                 if y != 0 {
-                    write_option(f, border.intersection)?;
+                    let split = border
+                        .inner_splits
+                        .get(&(y - 1))
+                        .copied()
+                        .or(border.inner.intersection)
+                        .map(|c| {
+                            joint(y - 1)
+                                .map(|(_, right)| right)
+                                .or_else(|| joint(y).map(|(left, _)| left))
+                                .unwrap_or(c)
+                        });
+                    write_option(f, split)?;
                 }
 
                 write!(f, "{}", cell[i])?;
             }
 
-            write_option(f, border.right_intersection)?;
+            let right = border
+                .inner
+                .right_intersection
+                .map(|c| joint(row.len() - 1).map_or(c, |(_, right)| right));
+            write_option(f, right)?;
 
             writeln!(f)?;
         }
@@ -358,6 +740,7 @@ pub struct Settings {
     text: Option<String>,
     ident: Option<Ident>,
     alignment: Option<Alignment>,
+    color: Option<Color>,
 }
 
 impl Settings {
@@ -388,6 +771,14 @@ impl Settings {
         self.alignment = Some(alignment);
         self
     }
+
+    /// Color method sets colors and text attributes for a cell.
+    ///
+    /// They're applied to the whole cell including its ident when a grid is rendered.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
 }
 
 /// Border structure represent all borders of a row
@@ -396,6 +787,7 @@ pub struct Border {
     top_line: LineStyle,
     bottom_line: LineStyle,
     inner: LineStyle,
+    inner_splits: HashMap<usize, char>,
 }
 
 impl Border {
@@ -405,11 +797,21 @@ impl Border {
             top_line: LineStyle::default(),
             bottom_line: LineStyle::default(),
             inner: LineStyle::default(),
+            inner_splits: HashMap::new(),
         };
 
         self
     }
 
+    /// The method removes all lines of a border
+    /// keeping intersections which are set for particular columns by [`Border::inner_split`].
+    pub fn clear_lines(&mut self) -> &mut Self {
+        self.top_line = LineStyle::default();
+        self.bottom_line = LineStyle::default();
+        self.inner = LineStyle::default();
+        self
+    }
+
     /// The method sets a top border line.
     ///
     /// * `main` - is a character which is used for building line.
@@ -456,6 +858,18 @@ impl Border {
         self
     }
 
+    /// The method removes a top border line.
+    pub fn clear_top(&mut self) -> &mut Self {
+        self.top_line = LineStyle::default();
+        self
+    }
+
+    /// The method removes a bottom border line.
+    pub fn clear_bottom(&mut self) -> &mut Self {
+        self.bottom_line = LineStyle::default();
+        self
+    }
+
     /// The method sets an inner row symbols.
     ///
     /// * `intersection` - a character which is used for internal separation on the line.
@@ -476,6 +890,13 @@ impl Border {
 
         self
     }
+
+    /// The method overrides an inner intersection character
+    /// which separates a `column` from the next one.
+    pub fn inner_split(&mut self, column: usize, intersection: char) -> &mut Self {
+        self.inner_splits.insert(column, intersection);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -496,7 +917,7 @@ impl LineStyle {
 }
 
 /// Entity a structure which represent a set of cells.
-#[derive(PartialEq, Eq, Debug, Hash)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
 pub enum Entity {
     /// All cells on the grid.
     Global,
@@ -514,6 +935,23 @@ struct Style {
     alignment: Alignment,
 }
 
+// EntityStyle holds settings which are set for an entity,
+// the ones which are not set are taken from a less specific entity.
+#[derive(Debug, Clone, Default)]
+struct EntityStyle {
+    ident: Option<Ident>,
+    alignment: Option<Alignment>,
+}
+
+impl From<Style> for EntityStyle {
+    fn from(style: Style) -> Self {
+        Self {
+            ident: Some(style.ident),
+            alignment: Some(style.alignment),
+        }
+    }
+}
+
 impl Default for Style {
     fn default() -> Self {
         Self {
@@ -564,6 +1002,150 @@ impl Alignment {
     }
 }
 
+/// Color represents colors and text attributes of a cell.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{AnsiColor, Color, Entity, Grid, Settings};
+///     let mut grid = Grid::new(1, 1);
+///     grid.set(
+///         Entity::Global,
+///         Settings::new().text("a").ident(1, 1, 0, 0).color(Color::new().fg(AnsiColor::Red).bold()),
+///     );
+///     assert_eq!(
+///          grid.to_string(),
+///          "+---+\n\
+///           |\u{1b}[1;31m a \u{1b}[0m|\n\
+///           +---+\n"
+///     )
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Color {
+    fg: Option<AnsiColor>,
+    bg: Option<AnsiColor>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Color {
+    /// New creates a color which changes nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fg sets a foreground color
+    pub fn fg(mut self, color: AnsiColor) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Bg sets a background color
+    pub fn bg(mut self, color: AnsiColor) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Bold makes a text bold
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Italic makes a text italic
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underline makes a text underlined
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    fn paint(&self, text: &str) -> String {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(String::from("1"));
+        }
+        if self.italic {
+            codes.push(String::from("3"));
+        }
+        if self.underline {
+            codes.push(String::from("4"));
+        }
+        if let Some(fg) = &self.fg {
+            codes.push(fg.code(30));
+        }
+        if let Some(bg) = &self.bg {
+            codes.push(bg.code(40));
+        }
+
+        if codes.is_empty() {
+            return text.to_owned();
+        }
+
+        format!("\u{1b}[{}m{}\u{1b}[0m", codes.join(";"), text)
+    }
+}
+
+/// AnsiColor represents a color of a text or a background.
+///
+/// It may be one of 16 basic colors, a color from a 256 palette or a true color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// A color from a 256 colors palette
+    Fixed(u8),
+    /// A true color
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    // base is 30 for a foreground and 40 for a background
+    fn code(&self, base: u8) -> String {
+        let basic = |i: u8| (base + i).to_string();
+        let bright = |i: u8| (base + 60 + i).to_string();
+        match *self {
+            AnsiColor::Black => basic(0),
+            AnsiColor::Red => basic(1),
+            AnsiColor::Green => basic(2),
+            AnsiColor::Yellow => basic(3),
+            AnsiColor::Blue => basic(4),
+            AnsiColor::Magenta => basic(5),
+            AnsiColor::Cyan => basic(6),
+            AnsiColor::White => basic(7),
+            AnsiColor::BrightBlack => bright(0),
+            AnsiColor::BrightRed => bright(1),
+            AnsiColor::BrightGreen => bright(2),
+            AnsiColor::BrightYellow => bright(3),
+            AnsiColor::BrightBlue => bright(4),
+            AnsiColor::BrightMagenta => bright(5),
+            AnsiColor::BrightCyan => bright(6),
+            AnsiColor::BrightWhite => bright(7),
+            AnsiColor::Fixed(n) => format!("{};5;{}", base + 8, n),
+            AnsiColor::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+}
+
 // I like old solution with Full/Frame/Off
 
 impl std::fmt::Display for Grid {
@@ -586,7 +1168,7 @@ impl std::fmt::Display for Grid {
                 build_line(f, &columns_width, &border.top_line)?;
             }
 
-            self.build_row(f, &row, &border.inner)?;
+            self.build_row(f, i, row, border)?;
             build_line(f, &columns_width, &border.bottom_line)?;
         }
 
@@ -594,6 +1176,21 @@ impl std::fmt::Display for Grid {
     }
 }
 
+// The function replaces trailing spaces of a joined line by its last character
+// so the line reaches borders of a cell.
+fn fill_joined_line(line: &str) -> String {
+    let trimmed = line.trim_end_matches(' ');
+    match trimmed.chars().last() {
+        Some(fill) => {
+            let width = string_width(line) - string_width(trimmed);
+            let mut line = trimmed.to_owned();
+            line.extend(iter::repeat(fill).take(width));
+            line
+        }
+        None => line.to_owned(),
+    }
+}
+
 fn build_cell(text: &str, style: Style, column_w: usize, row_h: usize) -> Vec<String> {
     let width = column_w - style.ident.left - style.ident.right;
     let height = row_h - style.ident.top - style.ident.bottom;
@@ -647,6 +1244,67 @@ fn build_line(
     Ok(())
 }
 
+fn shift_rows<V>(styles: &mut HashMap<Entity, V>, row: usize) {
+    *styles = styles
+        .drain()
+        .map(|(entity, style)| match entity {
+            Entity::Row(r) if r >= row => (Entity::Row(r + 1), style),
+            Entity::Cell(r, c) if r >= row => (Entity::Cell(r + 1, c), style),
+            entity => (entity, style),
+        })
+        .collect();
+}
+
+fn shift_columns<V>(styles: &mut HashMap<Entity, V>, column: usize) {
+    *styles = styles
+        .drain()
+        .map(|(entity, style)| match entity {
+            Entity::Column(c) if c >= column => (Entity::Column(c + 1), style),
+            Entity::Cell(r, c) if c >= column => (Entity::Cell(r, c + 1), style),
+            entity => (entity, style),
+        })
+        .collect();
+}
+
+fn drop_row<V>(styles: &mut HashMap<Entity, V>, row: usize) {
+    *styles = styles
+        .drain()
+        .filter_map(|(entity, style)| match entity {
+            Entity::Row(r) if r == row => None,
+            Entity::Cell(r, _) if r == row => None,
+            Entity::Row(r) if r > row => Some((Entity::Row(r - 1), style)),
+            Entity::Cell(r, c) if r > row => Some((Entity::Cell(r - 1, c), style)),
+            entity => Some((entity, style)),
+        })
+        .collect();
+}
+
+fn drop_column<V>(styles: &mut HashMap<Entity, V>, column: usize) {
+    *styles = styles
+        .drain()
+        .filter_map(|(entity, style)| match entity {
+            Entity::Column(c) if c == column => None,
+            Entity::Cell(_, c) if c == column => None,
+            Entity::Column(c) if c > column => Some((Entity::Column(c - 1), style)),
+            Entity::Cell(r, c) if c > column => Some((Entity::Cell(r, c - 1), style)),
+            entity => Some((entity, style)),
+        })
+        .collect();
+}
+
+fn swap_styles<V>(styles: &mut HashMap<Entity, V>, lhs: Entity, rhs: Entity) {
+    let lhs_style = styles.remove(&lhs);
+    let rhs_style = styles.remove(&rhs);
+
+    if let Some(style) = lhs_style {
+        styles.insert(rhs, style);
+    }
+
+    if let Some(style) = rhs_style {
+        styles.insert(lhs, style);
+    }
+}
+
 fn write_option<D: Display>(f: &mut std::fmt::Formatter<'_>, text: Option<D>) -> fmt::Result {
     match text {
         Some(text) => write!(f, "{}", text),
@@ -654,7 +1312,7 @@ fn write_option<D: Display>(f: &mut std::fmt::Formatter<'_>, text: Option<D>) ->
     }
 }
 
-fn split_text(text: &str, width: usize, height: usize) -> Vec<Cow<str>> {
+fn split_text(text: &str, width: usize, height: usize) -> Vec<Cow<'_, str>> {
     let mut lines = textwrap::wrap(text, width);
     while lines.len() < height {
         lines.push(str::repeat(" ", width).into())
@@ -663,23 +1321,29 @@ fn split_text(text: &str, width: usize, height: usize) -> Vec<Cow<str>> {
     lines
 }
 
+/// String_width returns a display width of the widest line of a text.
+///
+/// Wide characters like CJK and emojis take 2 columns.
 #[cfg(not(feature = "color"))]
-fn string_width(text: &str) -> usize {
+pub fn string_width(text: &str) -> usize {
     real_string_width(text)
 }
 
+/// String_width returns a display width of the widest line of a text.
+///
+/// Wide characters like CJK and emojis take 2 columns, ANSI escape sequences aren't counted.
 #[cfg(feature = "color")]
-fn string_width(text: &str) -> usize {
+pub fn string_width(text: &str) -> usize {
     let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
     let s = std::str::from_utf8(&b).unwrap();
-    real_string_width(&s)
+    real_string_width(s)
 }
 
 fn real_string_width(text: &str) -> usize {
     text.lines()
-        .map(|line| textwrap::core::display_width(line))
+        .map(textwrap::core::display_width)
         .max()
-        .unwrap_or_else(|| 0)
+        .unwrap_or(0)
 }
 
 #[cfg(test)]
@@ -863,6 +1527,187 @@ mod tests {
         )
     }
 
+    #[test]
+    fn grid_2x2_insert_row_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Cell(1, 1),
+            Settings::new().alignment(Alignment::Right),
+        );
+        grid.insert_row(1);
+        grid.set(Entity::Cell(1, 0), Settings::new().text("qwe   "));
+        grid.get_border_mut(1).clear_bottom();
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+------+---+\n\
+             |asd   |asd|\n\
+             +------+---+\n\
+             |qwe   |   |\n\
+             |asd   |asd|\n\
+             +------+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_insert_column_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Column(0),
+            Settings::new().alignment(Alignment::Right),
+        );
+        grid.insert_column(0);
+        grid.set(Entity::Column(0), Settings::new().text("q"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("qwe   "));
+        grid.get_border_mut(0).inner_split(0, '#');
+        grid.get_border_mut(1).inner_split(0, '#');
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-+------+---+\n\
+             |q#   qwe|asd|\n\
+             +-+------+---+\n\
+             |q#   asd|asd|\n\
+             +-+------+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_3x3_remove_row_with_styles_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Row(0), Settings::new().text("q"));
+        grid.set(
+            Entity::Row(2),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set(Entity::Cell(1, 0), Settings::new().text("zxcvb"));
+        grid.remove_row(1);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |q  |q  |\n\
+             +---+---+\n\
+             |qwe|qwe|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x3_remove_column_with_styles_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Column(0), Settings::new().text("q"));
+        grid.set(
+            Entity::Column(2),
+            Settings::new().text("a").alignment(Alignment::Right),
+        );
+        grid.set(Entity::Cell(0, 1), Settings::new().text("zxcvb"));
+        grid.get_border_mut(0)
+            .inner_split(0, '#')
+            .inner_split(1, '%');
+        grid.get_border_mut(1).inner_split(1, '%');
+        grid.remove_column(1);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-+-+\n\
+             |q#a|\n\
+             +-+-+\n\
+             |q|a|\n\
+             +-+-+\n"
+        )
+    }
+
+    #[test]
+    fn grid_footer_follows_inserted_and_removed_rows_test() {
+        let mut grid = Grid::new(4, 1);
+        grid.set_footer(2);
+
+        grid.insert_row(1);
+        assert_eq!(grid.count_footer_rows(), 2);
+        grid.insert_row(5);
+        assert_eq!(grid.count_footer_rows(), 3);
+        grid.remove_row(4);
+        assert_eq!(grid.count_footer_rows(), 2);
+        grid.remove_row(0);
+        assert_eq!(grid.count_footer_rows(), 2);
+
+        grid.set_footer(10);
+        assert_eq!(grid.count_footer_rows(), grid.count_rows());
+    }
+
+    #[test]
+    fn grid_concat_horizontal_test() {
+        let mut grid = Grid::new(1, 1);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        let mut other = Grid::new(2, 2);
+        other.set(
+            Entity::Global,
+            Settings::new().text("b").alignment(Alignment::Right),
+        );
+        other.set(Entity::Cell(1, 1), Settings::new().text("ccc"));
+        grid.concat_horizontal(&other);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-+-+---+\n\
+             |a|b|  b|\n\
+             +-+-+---+\n\
+             | |b|ccc|\n\
+             +-+-+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_concat_vertical_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("aaa"));
+        let mut other = Grid::new(2, 1);
+        other.set(
+            Entity::Global,
+            Settings::new().text("b").alignment(Alignment::Right),
+        );
+        other.set(Entity::Cell(1, 0), Settings::new().text("c"));
+        grid.concat_vertical(&other);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |aaa|aaa|\n\
+             +---+---+\n\
+             |  b|   |\n\
+             +---+---+\n\
+             |  c|   |\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_swap_rows_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Row(0), Settings::new().text("asd"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("asdasd"));
+        grid.set(
+            Entity::Cell(1, 1),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set(Entity::Cell(1, 0), Settings::new().text("zxc"));
+        grid.swap_rows(0, 1);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+------+\n\
+             |zxc|   qwe|\n\
+             +---+------+\n\
+             |asd|asdasd|\n\
+             +---+------+\n"
+        )
+    }
+
     #[test]
     fn grid_3x2_test() {
         let mut grid = Grid::new(3, 2);
@@ -941,10 +1786,7 @@ mod tests {
         {
             use colored::Colorize;
             let text = "Colored Text".red().to_string();
-            assert_eq!(
-                Alignment::Center.align(&text, 15),
-                format!(" {}  ", text),
-            );
+            assert_eq!(Alignment::Center.align(&text, 15), format!(" {}  ", text),);
         }
     }
 }
@@ -10,7 +10,7 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use papergrid::{Alignment, Entity, Grid, Settings};
+use papergrid::{Alignment, AnsiColor, Color, Entity, Grid, Settings};
 
 #[test]
 fn render() {
@@ -84,19 +84,17 @@ fn render_multilane_alignment() {
             .alignment(Alignment::Right),
     );
 
-    let expected = concat!(
-        "+----------------------------------------+---------+\n\
-         |                  left                  |right one|\n\
-         |                  cell                  |         |\n\
-         +----------------------------------------+---------+\n\
-         |the second column got the beginning here| and here|\n\
-         |                                        |       we|\n\
-         |                                        |      see|\n\
-         |                                        |        a|\n\
-         |                                        |     long|\n\
-         |                                        |   string|\n\
-         +----------------------------------------+---------+\n"
-    );
+    let expected = "+----------------------------------------+---------+\n\
+                    |                  left                  |right one|\n\
+                    |                  cell                  |         |\n\
+                    +----------------------------------------+---------+\n\
+                    |the second column got the beginning here| and here|\n\
+                    |                                        |       we|\n\
+                    |                                        |      see|\n\
+                    |                                        |        a|\n\
+                    |                                        |     long|\n\
+                    |                                        |   string|\n\
+                    +----------------------------------------+---------+\n";
 
     let g = grid.to_string();
     assert_eq!(expected, g);
@@ -481,3 +479,65 @@ fn render_empty_cell() {
 
 //     assert_eq!(expected, grid.to_string());
 // }
+
+#[test]
+fn render_color() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(Entity::Global, Settings::new().text("a").ident(1, 1, 0, 0));
+    grid.set(Entity::Cell(0, 0), Settings::new().text("abc"));
+    grid.set(
+        Entity::Row(1),
+        Settings::new().color(Color::new().bg(AnsiColor::Blue)),
+    );
+    grid.set(
+        Entity::Cell(1, 1),
+        Settings::new().color(Color::new().fg(AnsiColor::Rgb(1, 2, 3)).underline()),
+    );
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().color(
+            Color::new()
+                .fg(AnsiColor::Fixed(200))
+                .bg(AnsiColor::BrightWhite)
+                .italic(),
+        ),
+    );
+
+    let expected = concat!(
+        "+-----+---+\n",
+        "| abc |\u{1b}[3;38;5;200;107m a \u{1b}[0m|\n",
+        "+-----+---+\n",
+        "|\u{1b}[44m a   \u{1b}[0m|\u{1b}[4;38;2;1;2;3m a \u{1b}[0m|\n",
+        "+-----+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn render_color_moves_with_cells() {
+    let mut grid = Grid::new(2, 1);
+    grid.set(Entity::Cell(0, 0), Settings::new().text("0"));
+    grid.set(Entity::Cell(1, 0), Settings::new().text("1"));
+    grid.set(
+        Entity::Cell(1, 0),
+        Settings::new().color(Color::new().bold()),
+    );
+    grid.set(Entity::Cell(0, 0), Settings::new().color(Color::new()));
+
+    grid.swap_rows(0, 1);
+    grid.insert_row(0);
+    grid.insert_column(0);
+
+    let expected = concat!(
+        "++-+\n",
+        "|| |\n",
+        "++-+\n",
+        "||\u{1b}[1m1\u{1b}[0m|\n",
+        "++-+\n",
+        "||0|\n",
+        "++-+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
@@ -15,7 +15,7 @@ pub struct HorizontalAlignment<O: Object>(pub O, pub Alignment);
 
 impl<O: Object> TableOption for HorizontalAlignment<O> {
     fn change(&self, grid: &mut Grid) {
-        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+        for (row, column) in self.0.cells(grid) {
             grid.set(
                 Entity::Cell(row, column),
                 Settings::new().alignment(self.1.clone()),
@@ -0,0 +1,279 @@
+use std::{fmt, ops::Range};
+
+use papergrid::Grid;
+
+const WORD: usize = 64;
+
+/// CellSet is a set of cells of a `Grid` which is returned by an [`Object`](../trait.Object.html).
+///
+/// Cells are kept as a bitset in a row-major order,
+/// so combinations of objects stay cheap even on big tables.
+/// An iteration goes from left to right and from top to bottom.
+///
+/// Cells outside of the set bounds are ignored.
+///
+/// ```rust
+///     use tabled::CellSet;
+///
+///     let mut set = CellSet::new(2, 3);
+///     set.insert_segment(0..2, 1..2);
+///     set.insert(5, 5);
+///
+///     assert_eq!(set, vec![(0, 1), (1, 1)]);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CellSet {
+    count_rows: usize,
+    count_columns: usize,
+    words: Vec<u64>,
+}
+
+impl CellSet {
+    /// New creates an empty set for a grid of a given size.
+    pub fn new(count_rows: usize, count_columns: usize) -> Self {
+        let count_cells = count_rows * count_columns;
+        Self {
+            count_rows,
+            count_columns,
+            words: vec![0; (count_cells + WORD - 1) / WORD],
+        }
+    }
+
+    /// Empty creates an empty set for a given grid.
+    pub fn empty(grid: &Grid) -> Self {
+        Self::new(grid.count_rows(), grid.count_columns())
+    }
+
+    /// Insert adds a cell to the set.
+    pub fn insert(&mut self, row: usize, column: usize) {
+        if row < self.count_rows && column < self.count_columns {
+            let i = row * self.count_columns + column;
+            self.words[i / WORD] |= 1 << (i % WORD);
+        }
+    }
+
+    /// Contains checks whether a cell is in the set.
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        if row < self.count_rows && column < self.count_columns {
+            let i = row * self.count_columns + column;
+            self.words[i / WORD] & (1 << (i % WORD)) != 0
+        } else {
+            false
+        }
+    }
+
+    /// InsertSegment adds a rectangle of cells to the set.
+    pub fn insert_segment(&mut self, rows: Range<usize>, columns: Range<usize>) {
+        let rows = rows.start..rows.end.min(self.count_rows);
+        let columns = columns.start..columns.end.min(self.count_columns);
+        if rows.start >= rows.end || columns.start >= columns.end {
+            return;
+        }
+
+        if columns.start == 0 && columns.end == self.count_columns {
+            self.set_range(rows.start * self.count_columns..rows.end * self.count_columns);
+            return;
+        }
+
+        for row in rows {
+            let offset = row * self.count_columns;
+            self.set_range(offset + columns.start..offset + columns.end);
+        }
+    }
+
+    /// Len returns an amount of cells in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// IsEmpty checks whether there's no cells in the set.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Iter returns an iterator over cells of the set.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            count_columns: self.count_columns,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// Union adds all cells of other set.
+    pub fn union(&mut self, other: &CellSet) {
+        if self.has_same_size(other) {
+            self.zip_words(other, |l, r| l | r);
+        } else {
+            for (row, column) in other {
+                self.insert(row, column);
+            }
+        }
+    }
+
+    /// Intersection keeps only cells which are present in both sets.
+    pub fn intersection(&mut self, other: &CellSet) {
+        if self.has_same_size(other) {
+            self.zip_words(other, |l, r| l & r);
+        } else {
+            self.retain(|row, column| other.contains(row, column));
+        }
+    }
+
+    /// Difference removes all cells of other set.
+    pub fn difference(&mut self, other: &CellSet) {
+        if self.has_same_size(other) {
+            self.zip_words(other, |l, r| l & !r);
+        } else {
+            self.retain(|row, column| !other.contains(row, column));
+        }
+    }
+
+    /// SymmetricDifference keeps cells which are present only in one of the sets.
+    pub fn symmetric_difference(&mut self, other: &CellSet) {
+        if self.has_same_size(other) {
+            self.zip_words(other, |l, r| l ^ r);
+        } else {
+            let common = self
+                .iter()
+                .filter(|&(row, column)| other.contains(row, column));
+            let common = common.collect::<Vec<_>>();
+            self.union(other);
+            for (row, column) in common {
+                let i = row * self.count_columns + column;
+                self.words[i / WORD] &= !(1 << (i % WORD));
+            }
+        }
+    }
+
+    fn has_same_size(&self, other: &CellSet) -> bool {
+        self.count_rows == other.count_rows && self.count_columns == other.count_columns
+    }
+
+    fn zip_words(&mut self, other: &CellSet, f: impl Fn(u64, u64) -> u64) {
+        for (l, r) in self.words.iter_mut().zip(&other.words) {
+            *l = f(*l, *r);
+        }
+    }
+
+    fn retain(&mut self, f: impl Fn(usize, usize) -> bool) {
+        let mut set = CellSet::new(self.count_rows, self.count_columns);
+        for (row, column) in self.iter().filter(|&(row, column)| f(row, column)) {
+            set.insert(row, column);
+        }
+
+        *self = set;
+    }
+
+    fn set_range(&mut self, range: Range<usize>) {
+        let mut i = range.start;
+        while i < range.end {
+            let bit = i % WORD;
+            let count = (WORD - bit).min(range.end - i);
+            let mask = if count == WORD {
+                u64::MAX
+            } else {
+                ((1 << count) - 1) << bit
+            };
+
+            self.words[i / WORD] |= mask;
+            i += count;
+        }
+    }
+}
+
+impl fmt::Debug for CellSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq<Vec<(usize, usize)>> for CellSet {
+    fn eq(&self, other: &Vec<(usize, usize)>) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+impl<'a> IntoIterator for &'a CellSet {
+    type Item = (usize, usize);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for CellSet {
+    type Item = (usize, usize);
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            current: self.words.first().copied().unwrap_or(0),
+            index: 0,
+            set: self,
+        }
+    }
+}
+
+/// Iter is an iterator over cells of a [`CellSet`]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    count_columns: usize,
+    index: usize,
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_cell(
+            self.words,
+            self.count_columns,
+            &mut self.index,
+            &mut self.current,
+        )
+    }
+}
+
+/// IntoIter is an owning iterator over cells of a [`CellSet`]
+pub struct IntoIter {
+    set: CellSet,
+    index: usize,
+    current: u64,
+}
+
+impl Iterator for IntoIter {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_cell(
+            &self.set.words,
+            self.set.count_columns,
+            &mut self.index,
+            &mut self.current,
+        )
+    }
+}
+
+// The function looks up a next set bit, `current` holds not yet visited bits of a word by `index`
+fn next_cell(
+    words: &[u64],
+    count_columns: usize,
+    index: &mut usize,
+    current: &mut u64,
+) -> Option<(usize, usize)> {
+    while *current == 0 {
+        *index += 1;
+        *current = *words.get(*index)?;
+    }
+
+    let bit = current.trailing_zeros() as usize;
+    // drop the lowest set bit
+    *current &= *current - 1;
+
+    let i = *index * WORD + bit;
+    Some((i / count_columns, i % count_columns))
+}
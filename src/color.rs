@@ -0,0 +1,39 @@
+use papergrid::{Color, Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// Colorize sets colors and text attributes for cells of an object.
+///
+/// Colors are applied when a table is rendered,
+/// so they cover an ident and alignment spaces of a cell and don't affect a width of a column.
+/// It doesn't require the `color` feature.
+///
+/// ```rust
+///     use tabled::{table, AnsiColor, Color, Colorize, Head, Style};
+///
+///     let data = vec!["Grodno"];
+///
+///     let table = table!(&data, Style::psql(), Colorize(Head, Color::new().fg(AnsiColor::Green).bold()));
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             "\u{1b}[1;32m  &str  \u{1b}[0m\n",
+///             "--------\n",
+///             " Grodno \n",
+///         )
+///     );
+/// ```
+#[derive(Debug)]
+pub struct Colorize<O: Object>(pub O, pub Color);
+
+impl<O: Object> TableOption for Colorize<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid) {
+            grid.set(
+                Entity::Cell(row, column),
+                Settings::new().color(self.1.clone()),
+            )
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Concat appends a [`Grid`](../papergrid/struct.Grid.html) of another table to a table.
+///
+/// A grid can be built by [`build_grid`](./fn.build_grid.html) and modified by any option before concatenation.
+/// Cells keep their styles from both tables.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{build_grid, table, Concat, Disable, Style, TableOption};
+///     let languages = vec![("Rust", 2010), ("C", 1972)];
+///     let authors = vec!["Graydon Hoare", "Dennis Ritchie"];
+///     let table = table!(
+///         &languages,
+///         Concat::horizontal(build_grid(&authors)),
+///         Style::psql(),
+///     );
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             " &str | i32  |      &str      \n",
+///             "------+------+----------------\n",
+///             " Rust | 2010 | Graydon Hoare  \n",
+///             "  C   | 1972 | Dennis Ritchie \n",
+///         )
+///     );
+///
+///     let mut more_languages = build_grid(&[("Go", 2009)]);
+///     Disable::Row(..1).change(&mut more_languages);
+///
+///     let table = table!(&languages, Concat::vertical(more_languages), Style::psql());
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             " &str | i32  \n",
+///             "------+------\n",
+///             " Rust | 2010 \n",
+///             "  C   | 1972 \n",
+///             "  Go  | 2009 \n",
+///         )
+///     );
+/// ```
+pub struct Concat {
+    grid: Grid,
+    direction: ConcatDirection,
+}
+
+enum ConcatDirection {
+    Horizontal,
+    Vertical,
+}
+
+impl Concat {
+    /// Horizontal appends columns of a grid to the right side of a table.
+    ///
+    /// A table with less rows is padded with empty cells.
+    pub fn horizontal(grid: Grid) -> Self {
+        Self {
+            grid,
+            direction: ConcatDirection::Horizontal,
+        }
+    }
+
+    /// Vertical appends rows of a grid to the bottom of a table.
+    ///
+    /// A table with less columns is padded with empty cells.
+    /// A header of the grid is appended as well, so it may be worth to remove it first.
+    pub fn vertical(grid: Grid) -> Self {
+        Self {
+            grid,
+            direction: ConcatDirection::Vertical,
+        }
+    }
+}
+
+impl TableOption for Concat {
+    fn change(&self, grid: &mut Grid) {
+        match self.direction {
+            ConcatDirection::Horizontal => grid.concat_horizontal(&self.grid),
+            ConcatDirection::Vertical => grid.concat_vertical(&self.grid),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::RangeBounds;
 
 use papergrid::Grid;
 
-use crate::{bounds_to_usize, TableOption};
+use crate::{bounds_to_usize, ColumnNames, TableOption};
 
 /// Disable represent a disable setting for a [`table` macros](./macro.table.html)
 ///
@@ -12,8 +12,22 @@ use crate::{bounds_to_usize, TableOption};
 ///     let table = table!(&data, Disable::Row(..1));
 /// ```
 ///
+/// Columns can also be located by their headers, so the setting doesn't break when a field is added.
+/// Names refer only to columns, so a [`Disable::Row`] with names leaves a table as it is.
+///
+/// ```rust
+///     use tabled::{table, Column, Disable, Style};
+///     let data = vec![("Grodno", 356_900, true)];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Disable::Column(Column::by_names(vec!["i32", "bool"]))
+///     );
+///
+///     assert_eq!(table, "  &str  \n--------\n Grodno \n");
+/// ```
 #[derive(Debug)]
-pub enum Disable<R: RangeBounds<usize>> {
+pub enum Disable<R> {
     /// Columns of the grid. Range is used to locate columns.
     Column(R),
     /// Rows of the grid. Range is used to locate rows.
@@ -43,3 +57,13 @@ impl<R: RangeBounds<usize>> TableOption for Disable<R> {
         }
     }
 }
+
+impl TableOption for Disable<ColumnNames> {
+    fn change(&self, grid: &mut Grid) {
+        if let Self::Column(names) = self {
+            for (shifted, i) in names.columns(grid).into_iter().enumerate() {
+                grid.remove_column(i - shifted);
+            }
+        }
+    }
+}
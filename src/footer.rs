@@ -0,0 +1,334 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{style::Line, CellSet, Object, TableOption};
+
+/// Footer appends a row which is computed from the columns of a table.
+///
+/// The first row is considered to be a header so it's not involved in computation.
+/// Cells are parsed as numbers in a relaxed way, so thousands separators, currency signs and units are ignored.
+/// Cells which are not numbers, like `i32`, `v1.2.3` or `2021-06-01`, are skipped.
+///
+/// The footer row is marked in a grid, so a [`Sort`](./struct.Sort.html) keeps it at the bottom
+/// and a next Footer aggregates only rows above it.
+/// The [`Footer::line`] is a bottom border of the row above the footer though,
+/// so a [`Style`](./struct.Style.html) set after the Footer replaces it by its own split line.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Aggregate, Column, Footer, Style, style::Line};
+///     let data = vec![("Coffee", "$1,200.50"), ("Tea", "$99.5"), ("Water", "free")];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Footer::new()
+///             .label("Total")
+///             .aggregate(Column(1..2), Aggregate::Sum)
+///             .line(Line::short('=', '+')),
+///     );
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             "  &str  |   &str    \n",
+///             "--------+-----------\n",
+///             " Coffee | $1,200.50 \n",
+///             "  Tea   |   $99.5   \n",
+///             " Water  |   free    \n",
+///             "========+===========\n",
+///             " Total  |  1300.00  \n",
+///         )
+///     );
+/// ```
+pub struct Footer {
+    label: Option<String>,
+    aggregates: Vec<(ColumnsFn, Aggregate)>,
+    line: Option<Line>,
+}
+
+type ColumnsFn = Box<dyn Fn(&Grid) -> CellSet>;
+type AggregateFn = Box<dyn Fn(&[&str]) -> String>;
+
+/// Aggregate is a function which computes a footer cell from cells of a column.
+pub enum Aggregate {
+    /// A sum of numeric cells.
+    Sum,
+    /// An arithmetic mean of numeric cells.
+    Mean,
+    /// The minimum of numeric cells.
+    Min,
+    /// The maximum of numeric cells.
+    Max,
+    /// An amount of not empty cells.
+    Count,
+    /// A custom function which takes all cells of a column.
+    Custom(AggregateFn),
+}
+
+impl Aggregate {
+    /// Custom creates an aggregate from a function
+    pub fn custom<F: Fn(&[&str]) -> String + 'static>(f: F) -> Self {
+        Self::Custom(Box::new(f))
+    }
+
+    fn compute(&self, cells: &[&str]) -> String {
+        let numbers = cells
+            .iter()
+            .filter_map(|cell| parse_number(cell))
+            .collect::<Vec<_>>();
+        let precision = numbers.iter().map(|(_, p)| *p).max().unwrap_or(0);
+        let values = numbers.iter().map(|(n, _)| *n);
+
+        match self {
+            Self::Count => cells
+                .iter()
+                .filter(|cell| !cell.trim().is_empty())
+                .count()
+                .to_string(),
+            Self::Sum => format_number(values.fold(0.0, |sum, n| sum + n), precision, precision),
+            Self::Mean if numbers.is_empty() => String::new(),
+            Self::Mean => {
+                let mean = values.fold(0.0, |sum, n| sum + n) / numbers.len() as f64;
+                format_number(mean, precision, precision + 2)
+            }
+            Self::Min => values
+                .reduce(f64::min)
+                .map(|n| format_number(n, precision, precision))
+                .unwrap_or_default(),
+            Self::Max => values
+                .reduce(f64::max)
+                .map(|n| format_number(n, precision, precision))
+                .unwrap_or_default(),
+            Self::Custom(f) => f(cells),
+        }
+    }
+}
+
+impl Footer {
+    /// New creates an empty footer
+    pub fn new() -> Self {
+        Self {
+            label: None,
+            aggregates: Vec::new(),
+            line: None,
+        }
+    }
+
+    /// Label sets a text of the first column of a footer.
+    ///
+    /// It's ignored if the first column has an aggregate.
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Aggregate sets a function which computes footer cells of columns of an object.
+    pub fn aggregate<O: Object + 'static>(mut self, columns: O, aggregate: Aggregate) -> Self {
+        let columns = Box::new(move |grid: &Grid| columns.cells(grid));
+        self.aggregates.push((columns, aggregate));
+        self
+    }
+
+    /// Line sets a line which separates a footer from the rest of the table.
+    pub fn line(mut self, line: Line) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl Default for Footer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Footer {
+    fn change(&self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0 {
+            return;
+        }
+
+        // rows of footers which are already set are not involved in computation
+        let count_footer_rows = grid.count_footer_rows();
+        let count_body_rows = count_rows - count_footer_rows;
+
+        let mut footer = vec![String::new(); count_columns];
+        if let (Some(label), Some(cell)) = (&self.label, footer.first_mut()) {
+            *cell = label.clone();
+        }
+
+        for (columns, aggregate) in &self.aggregates {
+            let mut columns = columns(grid)
+                .into_iter()
+                .map(|(_, column)| column)
+                .filter(|column| *column < count_columns)
+                .collect::<Vec<_>>();
+            columns.sort_unstable();
+            columns.dedup();
+
+            for column in columns {
+                let cells = (1..count_body_rows)
+                    .map(|row| grid.get_cell_content(row, column).to_owned())
+                    .collect::<Vec<_>>();
+                let cells = cells.iter().map(String::as_str).collect::<Vec<_>>();
+
+                footer[column] = aggregate.compute(&cells);
+            }
+        }
+
+        // The footer takes a bottom frame from the last row,
+        // which in its turn gets a footer line.
+        // A header line is kept in case there's no data in the table.
+        let last_row = count_rows - 1;
+        let mut border = grid.get_border_mut(last_row).clone();
+        border.clear_top();
+
+        if last_row > 0 {
+            let last_border = grid.get_border_mut(last_row);
+            match &self.line {
+                Some(line) => last_border.bottom(
+                    line.main,
+                    line.intersection,
+                    line.left_corner,
+                    line.right_corner,
+                ),
+                None => last_border.clear_bottom(),
+            };
+        }
+
+        grid.insert_row(count_rows);
+        *grid.get_border_mut(count_rows) = border;
+
+        for (column, text) in footer.into_iter().enumerate() {
+            grid.set(Entity::Cell(count_rows, column), Settings::new().text(text));
+        }
+
+        grid.set_footer(count_footer_rows + 1);
+    }
+}
+
+// The function returns a number and an amount of digits after a decimal point.
+//
+// A number may be surrounded only by whitespaces, currency signs and units,
+// so texts like `i32`, `v1.2.3` or `2021-06-01` are not considered to be numbers.
+fn parse_number(text: &str) -> Option<(f64, usize)> {
+    let text = strip_ansi_sequences(text);
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+
+    let mut prefix = &text[..start];
+    let is_fraction = prefix.ends_with('.');
+    if is_fraction {
+        prefix = &prefix[..prefix.len() - 1];
+    }
+
+    let mut is_negative = prefix.ends_with('-');
+    if is_negative {
+        prefix = &prefix[..prefix.len() - 1];
+    }
+
+    let trimmed = prefix.trim_end();
+    let is_spaced = trimmed.len() < prefix.len();
+    prefix = trimmed;
+    if prefix.ends_with(is_currency_sign) {
+        prefix = prefix[..prefix.len() - prefix.chars().last()?.len_utf8()].trim_end();
+        if !is_negative && prefix.ends_with('-') {
+            is_negative = true;
+            prefix = prefix[..prefix.len() - 1].trim_end();
+        }
+    } else if is_spaced {
+        // a unit before a number like `USD 10` must be separated from it
+        prefix = prefix.trim_end_matches(char::is_alphabetic).trim_end();
+    }
+
+    if !prefix.is_empty() {
+        return None;
+    }
+
+    let mut number = String::new();
+    if is_negative {
+        number.push('-');
+    }
+
+    if is_fraction {
+        number.push('.');
+    }
+
+    let mut precision = 0;
+    let mut is_fraction = is_fraction;
+    let mut end = text.len();
+    for (i, c) in text[start..].char_indices() {
+        match c {
+            '0'..='9' => {
+                number.push(c);
+                if is_fraction {
+                    precision += 1;
+                }
+            }
+            '.' if !is_fraction => {
+                number.push(c);
+                is_fraction = true;
+            }
+            // thousands separators
+            ',' | '_' | '\'' if !is_fraction => {}
+            _ => {
+                end = start + i;
+                break;
+            }
+        }
+    }
+
+    let suffix = text[end..].trim();
+    let is_unit = suffix
+        .chars()
+        .all(|c| c.is_alphabetic() || is_currency_sign(c) || matches!(c, '%' | '/' | '°'));
+    if !is_unit {
+        return None;
+    }
+
+    number.parse().ok().map(|n| (n, precision))
+}
+
+fn is_currency_sign(c: char) -> bool {
+    matches!(
+        c,
+        '$' | '¢' | '£' | '¥' | '€' | '₴' | '₹' | '₽' | '₩' | '₺' | '₿'
+    )
+}
+
+fn strip_ansi_sequences(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // skip a control sequence up to its final byte
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+// The function prints at least `min` and at most `max` digits after a decimal point.
+fn format_number(n: f64, min: usize, max: usize) -> String {
+    let mut text = format!("{:.*}", max, n);
+    if max > min {
+        let dot = text.find('.').expect("a number must have a decimal point");
+        while text.len() > dot + 1 + min && text.ends_with('0') {
+            text.pop();
+        }
+
+        if text.ends_with('.') {
+            text.pop();
+        }
+    }
+
+    text
+}
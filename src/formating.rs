@@ -1,19 +1,11 @@
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{Alignment, Entity, Grid, Settings};
 
 use crate::{Object, TableOption};
 
-/// ChangeRing a structure which modifies a `Grid` in a series of rounds.
-/// It calls a function in a cycle for a set of cells.
-///
-/// So for example if we have 2 function in a pool `[foo, bar]` and we run it against a [`Head`](./struct.Head.html),
-/// where `Grid` will contain 5 columns. `ChangeRing` will call `foo` on the first cell, `bar` on the second then
-/// it again run `foo` on the third and so on.
+/// Format a structure which modifies content of cells of an object by a function.
 ///
 /// # Example
 ///
-/// Note that settings isn't applied by row they applied to cells in a row.
-/// Look at these two examples.
-///
 /// ```rust
 ///    # use tabled::{table, Format, Row};
 ///     let data = vec![
@@ -42,7 +34,7 @@ pub struct Format<O: Object, F: Fn(&str) -> String>(pub O, pub F);
 
 impl<O: Object, F: Fn(&str) -> String> TableOption for Format<O, F> {
     fn change(&self, grid: &mut Grid) {
-        let cells = self.0.cells(grid.count_rows(), grid.count_columns());
+        let cells = self.0.cells(grid);
         for (row, column) in cells {
             let content = grid.get_cell_content(row, column);
             let content = (self.1)(content);
@@ -51,6 +43,215 @@ impl<O: Object, F: Fn(&str) -> String> TableOption for Format<O, F> {
     }
 }
 
+/// FormatCell is like [`Format`] but a function also gets a position of a cell
+/// and a header of its column.
+///
+/// Headers are taken before any changes are made by the option.
+///
+/// ```rust
+///     use tabled::{table, Body, FormatCell};
+///
+///     let data = vec![("Grodno", 356_900, 61.0), ("Minsk", 2_009_786, 409.5)];
+///
+///     let table = table!(
+///         &data,
+///         FormatCell(Body, |s, cell| match cell.header {
+///             "f64" => format!("{} km2", s),
+///             _ if cell.column == 0 => format!("{}. {}", cell.row, s),
+///             _ => s.to_owned(),
+///         })
+///     );
+///
+///     assert_eq!(table, "+-----------+---------+-----------+\n\
+///                        |   &str    |   i32   |    f64    |\n\
+///                        +-----------+---------+-----------+\n\
+///                        | 1. Grodno | 356900  |  61 km2   |\n\
+///                        +-----------+---------+-----------+\n\
+///                        | 2. Minsk  | 2009786 | 409.5 km2 |\n\
+///                        +-----------+---------+-----------+\n");
+/// ```
+pub struct FormatCell<O, F>(pub O, pub F)
+where
+    O: Object,
+    F: Fn(&str, &CellInfo<'_>) -> String;
+
+/// CellInfo describes a cell which is passed to [`FormatCell`]
+#[derive(Debug, Clone, Copy)]
+pub struct CellInfo<'a> {
+    /// A row index of the cell, the header row is 0
+    pub row: usize,
+    /// A column index of the cell
+    pub column: usize,
+    /// A content of the header of the cell's column
+    pub header: &'a str,
+}
+
+impl<O, F> TableOption for FormatCell<O, F>
+where
+    O: Object,
+    F: Fn(&str, &CellInfo<'_>) -> String,
+{
+    fn change(&self, grid: &mut Grid) {
+        let headers = headers(grid);
+        let cells = self.0.cells(grid);
+        for (row, column) in cells {
+            let info = CellInfo {
+                row,
+                column,
+                header: &headers[column],
+            };
+
+            let content = grid.get_cell_content(row, column);
+            let content = (self.1)(content, &info);
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+fn headers(grid: &Grid) -> Vec<String> {
+    if grid.count_rows() == 0 {
+        return vec![String::new(); grid.count_columns()];
+    }
+
+    (0..grid.count_columns())
+        .map(|column| grid.get_cell_content(0, column).to_owned())
+        .collect()
+}
+
+/// ChangeRing a structure which modifies a `Grid` in a series of rounds.
+/// It calls a function in a cycle for a set of cells.
+///
+/// So for example if we have 2 function in a pool `[foo, bar]` and we run it against a [`Head`](./struct.Head.html),
+/// where `Grid` will contain 5 columns. `ChangeRing` will call `foo` on the first cell, `bar` on the second then
+/// it again run `foo` on the third and so on.
+///
+/// A pool may consist of functions which change content like in [`Format`]
+/// or of [`Alignment`](./enum.Alignment.html)s.
+///
+/// # Example
+///
+/// Note that by default settings isn't applied by row they applied to cells in a row.
+/// Look at these two examples.
+///
+/// ```rust
+///     use tabled::{table, ChangeRing, Full, Row};
+///
+///     let data = vec![(0, "Grodno"), (1, "Minsk")];
+///
+///     let pool = vec![|s: &str| format!("+{}", s), |s: &str| format!("-{}", s)];
+///     let table = table!(&data, ChangeRing::new(Row(1..), pool));
+///
+///     assert_eq!(table, "+-----+---------+\n\
+///                        | i32 |  &str   |\n\
+///                        +-----+---------+\n\
+///                        | +0  | -Grodno |\n\
+///                        +-----+---------+\n\
+///                        | +1  | -Minsk  |\n\
+///                        +-----+---------+\n");
+///
+///     let pool = vec![|s: &str| format!("+{}", s), |s: &str| format!("-{}", s)];
+///     let table = table!(&data, ChangeRing::new(Full, pool).by_rows());
+///
+///     assert_eq!(table, "+------+---------+\n\
+///                        | +i32 |  +&str  |\n\
+///                        +------+---------+\n\
+///                        |  -0  | -Grodno |\n\
+///                        +------+---------+\n\
+///                        |  +1  | +Minsk  |\n\
+///                        +------+---------+\n");
+/// ```
+pub struct ChangeRing<O: Object, C: CellChange> {
+    object: O,
+    pool: Vec<C>,
+    round: Round,
+}
+
+enum Round {
+    Cell,
+    Row,
+    Column,
+}
+
+impl<O: Object, C: CellChange> ChangeRing<O, C> {
+    /// New creates a ring which goes through a pool cell by cell.
+    pub fn new(object: O, pool: Vec<C>) -> Self {
+        Self {
+            object,
+            pool,
+            round: Round::Cell,
+        }
+    }
+
+    /// ByRows makes all cells of a row to be changed by the same element of a pool.
+    pub fn by_rows(mut self) -> Self {
+        self.round = Round::Row;
+        self
+    }
+
+    /// ByColumns makes all cells of a column to be changed by the same element of a pool.
+    pub fn by_columns(mut self) -> Self {
+        self.round = Round::Column;
+        self
+    }
+}
+
+impl<O: Object, C: CellChange> TableOption for ChangeRing<O, C> {
+    fn change(&self, grid: &mut Grid) {
+        if self.pool.is_empty() {
+            return;
+        }
+
+        let cells = self.object.cells(grid);
+        let columns = match self.round {
+            Round::Column => {
+                let mut columns = cells.iter().map(|(_, column)| column).collect::<Vec<_>>();
+                columns.sort_unstable();
+                columns.dedup();
+                columns
+            }
+            _ => Vec::new(),
+        };
+
+        let mut last_row = None;
+        let mut count_rows = 0;
+        for (i, (row, column)) in cells.into_iter().enumerate() {
+            let round = match self.round {
+                Round::Cell => i,
+                Round::Row => {
+                    if matches!(last_row, Some(last) if last != row) {
+                        count_rows += 1;
+                    }
+
+                    last_row = Some(row);
+                    count_rows
+                }
+                Round::Column => columns.binary_search(&column).unwrap_or_default(),
+            };
+
+            self.pool[round % self.pool.len()].change_cell(grid, row, column);
+        }
+    }
+}
+
+/// CellChange is a modification of a single cell which can be used in a [`ChangeRing`]
+pub trait CellChange {
+    /// ChangeCell applies the modification to a cell.
+    fn change_cell(&self, grid: &mut Grid, row: usize, column: usize);
+}
+
+impl<F: Fn(&str) -> String> CellChange for F {
+    fn change_cell(&self, grid: &mut Grid, row: usize, column: usize) {
+        let content = (self)(grid.get_cell_content(row, column));
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+impl CellChange for Alignment {
+    fn change_cell(&self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set_alignment(Entity::Cell(row, column), self.clone())
+    }
+}
+
 /// Multiline a helper function for changing multiline content of cell by rows not as a whole.
 ///
 /// ```rust,no_run
@@ -58,5 +259,5 @@ impl<O: Object, F: Fn(&str) -> String> TableOption for Format<O, F> {
 ///     let data: Vec<&'static str> = Vec::new();
 ///     table!(&data, Format(Full, multiline(|s| { format!("{}", s) })));
 pub fn multiline<F: 'static + Fn(&str) -> String>(f: F) -> Box<dyn Fn(&str) -> String> {
-    Box::new(move |s: &str| s.lines().map(|s| f(s)).collect::<Vec<_>>().join("\n"))
+    Box::new(move |s: &str| s.lines().map(&f).collect::<Vec<_>>().join("\n"))
 }
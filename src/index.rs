@@ -0,0 +1,136 @@
+use papergrid::{Alignment, Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Index prepends a column with row numbers or labels to a table.
+///
+/// The first row is considered to be a header so it gets a header of the index column.
+/// A split and an alignment can be set to make the index look like a header column.
+///
+/// Rows marked as a footer by a [`Footer`](./struct.Footer.html) are left without a label.
+/// The split replaces an inner split of a [`Style`](./struct.Style.html) only after the index column,
+/// and it's kept when a `Style` is set after the Index.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Alignment, Index, Style};
+///     let data = vec!["Rust", "C", "Go"];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Index::new().header("#").start(1).split('‖').alignment(Alignment::Left),
+///     );
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             " # ‖ &str \n",
+///             "---+------\n",
+///             " 1 ‖ Rust \n",
+///             " 2 ‖  C   \n",
+///             " 3 ‖  Go  \n",
+///         )
+///     );
+/// ```
+pub struct Index {
+    header: String,
+    start: usize,
+    labels: Option<LabelFn>,
+    split: Option<char>,
+    alignment: Option<Alignment>,
+}
+
+type LabelFn = Box<dyn Fn(usize) -> String>;
+
+impl Index {
+    /// New creates an index with numbers started from 0 and an empty header
+    pub fn new() -> Self {
+        Self {
+            header: String::new(),
+            start: 0,
+            labels: None,
+            split: None,
+            alignment: None,
+        }
+    }
+
+    /// Header sets a text of an index column header
+    pub fn header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Start sets a number of the first row
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Labels sets a function which makes a label from a row number.
+    ///
+    /// Row numbers are started from 0 regardless of [`Index::start`].
+    pub fn labels<F: Fn(usize) -> String + 'static>(mut self, f: F) -> Self {
+        self.labels = Some(Box::new(f));
+        self
+    }
+
+    /// Split sets a character which separates an index column from the rest of the table
+    pub fn split(mut self, split: char) -> Self {
+        self.split = Some(split);
+        self
+    }
+
+    /// Alignment sets an alignment of an index column
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    fn label(&self, row: usize) -> String {
+        match &self.labels {
+            Some(f) => f(row),
+            None => (self.start + row).to_string(),
+        }
+    }
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Index {
+    fn change(&self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if count_rows == 0 {
+            return;
+        }
+
+        grid.insert_column(0);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text(self.header.clone()),
+        );
+
+        // footer rows are left without a label
+        let count_body_rows = count_rows - grid.count_footer_rows();
+        for row in 1..count_body_rows {
+            grid.set(
+                Entity::Cell(row, 0),
+                Settings::new().text(self.label(row - 1)),
+            );
+        }
+
+        if let Some(alignment) = &self.alignment {
+            grid.set_alignment(Entity::Column(0), alignment.clone());
+        }
+
+        if let Some(split) = self.split {
+            for row in 0..count_rows {
+                grid.get_border_mut(row).inner_split(0, split);
+            }
+        }
+    }
+}
@@ -131,42 +131,156 @@
 //!
 
 mod alignment;
+pub mod cell_set;
+mod color;
+mod concat;
 mod disable;
+mod footer;
 mod formating;
+mod index;
+mod nested;
 mod object;
+mod records;
+mod sort;
 pub mod style;
+mod width;
 
-pub use crate::{alignment::*, disable::*, formating::*, object::*, style::Style};
+pub use crate::{
+    alignment::*, cell_set::CellSet, color::*, concat::*, disable::*, footer::*, formating::*,
+    index::*, nested::*, object::*, records::*, sort::*, style::Style, width::*,
+};
 
-pub use papergrid::Alignment;
+pub use papergrid::{Alignment, AnsiColor, Color};
 pub use tabled_derive::Tabled;
 
 use papergrid::{Entity, Grid, Settings};
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
 
 /// Tabled a trait responsible for providing a header filds and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
 ///
 /// ```text
-///     Self::headers().len() == self.fields().len()
+///     Self::headers().len() == self.fields().len() == Self::LENGTH
 /// ```
+///
+/// Cells are [`Cow`]s so a value which is a string already can be borrowed rather than copied.
 pub trait Tabled {
+    /// An amount of columns
+    const LENGTH: usize;
     /// Fields must return a list of cell in a row
-    fn fields(&self) -> Vec<String>;
+    fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers return a list of names for columns
-    fn headers() -> Vec<String>;
+    fn headers() -> Vec<Cow<'static, str>>;
+    /// Fields_into writes cells of a row at the end of a buffer.
+    ///
+    /// It's used to reuse a buffer for all rows,
+    /// the default implementation calls [`Tabled::fields`].
+    fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+        buf.extend(self.fields());
+    }
+    /// Columns returns a list of settings for columns which are applied by [`build_grid`](./fn.build_grid.html).
+    ///
+    /// It may be shorter than `headers`, in which case the rest columns have default settings.
+    fn columns() -> Vec<ColumnMeta> {
+        Vec::new()
+    }
 }
 
 impl<T> Tabled for &T
 where
     T: Tabled,
 {
-    fn fields(&self) -> Vec<String> {
+    const LENGTH: usize = T::LENGTH;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
     }
-    fn headers() -> Vec<String> {
+    fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+        T::fields_into(self, buf)
+    }
+    fn columns() -> Vec<ColumnMeta> {
+        T::columns()
+    }
+}
+
+/// ColumnMeta represents settings of a column which are known by a type.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///     use tabled::{table, Alignment, ColumnMeta, Tabled};
+///
+///     struct Price(f64);
+///
+///     impl Tabled for Price {
+///         const LENGTH: usize = 1;
+///
+///         fn fields(&self) -> Vec<Cow<'_, str>> {
+///             vec![format!("{:.2}", self.0).into()]
+///         }
+///
+///         fn headers() -> Vec<Cow<'static, str>> {
+///             vec!["price".into()]
+///         }
+///
+///         fn columns() -> Vec<ColumnMeta> {
+///             vec![ColumnMeta::new().alignment(Alignment::Right)]
+///         }
+///     }
+///
+///     let table = table!(&[Price(1.5), Price(100.0)]);
+///
+///     assert_eq!(
+///         table,
+///         "+--------+\n\
+///          |  price |\n\
+///          +--------+\n\
+///          |   1.50 |\n\
+///          +--------+\n\
+///          | 100.00 |\n\
+///          +--------+\n"
+///     );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMeta {
+    alignment: Option<Alignment>,
+    max_width: Option<usize>,
+    hidden: bool,
+}
+
+impl ColumnMeta {
+    /// New creates a column with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alignment sets an alignment of cells of a column
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Max_width sets a width to which data cells of a column are truncated, a header is kept as it is
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Hidden makes a column not being displayed
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
 }
 
 /// A trait for configuring a `Grid`.
@@ -238,11 +352,20 @@ macro_rules! table {
 
 /// Build_grid function build a [`Grid`](../papergrid/struct.Grid.html) from a data.
 /// A [`table` macros](./macro.table.html) should be prefered over this function.
+///
+/// Settings of columns returned by [`Tabled::columns`] are applied to the grid.
 pub fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
-    let headers = T::headers();
-    let obj: Vec<Vec<String>> = iter.into_iter().map(|t| t.fields()).collect();
+    let mut columns = T::columns();
+    columns.resize(T::LENGTH, ColumnMeta::default());
 
-    let mut grid = Grid::new(obj.len() + 1, headers.len());
+    // hidden columns are skipped rather than removed so there's no need to shift styles
+    let visible = (0..T::LENGTH)
+        .filter(|&column| !columns[column].hidden)
+        .collect::<Vec<_>>();
+
+    let records = iter.into_iter().collect::<Vec<_>>();
+
+    let mut grid = Grid::new(records.len() + 1, visible.len());
 
     // it's crusial to set a global setting rather than a setting for an each cell
     // as it will be hard to override that since how Grid::style method works
@@ -253,20 +376,45 @@ pub fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
             .alignment(Alignment::Center),
     );
 
-    for (i, h) in headers.iter().enumerate() {
-        grid.set(Entity::Cell(0, i), Settings::new().text(h));
+    let headers = T::headers();
+    for (i, &column) in visible.iter().enumerate() {
+        grid.set(
+            Entity::Cell(0, i),
+            Settings::new().text(headers[column].as_ref()),
+        );
     }
 
     let mut row = 1;
-    for fields in &obj {
-        for (column, field) in fields.iter().enumerate() {
-            grid.set(Entity::Cell(row, column), Settings::new().text(field));
-        }
+    let mut fields = Vec::with_capacity(T::LENGTH);
+    for record in &records {
+        record.fields_into(&mut fields);
 
         // don't show off a empty data array
         // currently it's possible when `#[header(hidden)]` attribute used for a enum
-        if !fields.is_empty() {
-            row += 1;
+        if fields.is_empty() {
+            continue;
+        }
+
+        let cells = fields
+            .drain(..)
+            .enumerate()
+            .filter(|(column, _)| !columns[*column].hidden);
+        for (i, (column, field)) in cells.enumerate() {
+            // headers are kept as they are so only data gets truncated
+            let field = match columns[column].max_width {
+                Some(width) => Cow::Owned(width::truncate(&field, width)),
+                None => field,
+            };
+
+            grid.set(Entity::Cell(row, i), Settings::new().text(field));
+        }
+
+        row += 1;
+    }
+
+    for (i, &column) in visible.iter().enumerate() {
+        if let Some(alignment) = &columns[column].alignment {
+            grid.set_alignment(Entity::Column(i), alignment.clone());
         }
     }
 
@@ -276,18 +424,34 @@ pub fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
 macro_rules! tuple_table {
     ( $($name:ident)+ ) => {
         impl<$($name: Tabled),+> Tabled for ($($name,)+){
-            fn fields(&self) -> Vec<String> {
+            const LENGTH: usize = 0 $(+ $name::LENGTH)+;
+
+            fn fields(&self) -> Vec<Cow<'_, str>> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                self.fields_into(&mut fields);
+                fields
+            }
+
+            fn headers() -> Vec<Cow<'static, str>> {
+                let mut headers = Vec::with_capacity(Self::LENGTH);
+                $(headers.extend($name::headers());)+
+                headers
+            }
+
+            fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
                 #![allow(non_snake_case)]
                 let ($($name,)+) = self;
-                let mut fields = Vec::new();
-                $(fields.append(&mut $name.fields());)+
-                fields
+                $($name.fields_into(buf);)+
             }
 
-            fn headers() -> Vec<String> {
-                let mut fields = Vec::new();
-                $(fields.append(&mut $name::headers());)+
-                fields
+            fn columns() -> Vec<ColumnMeta> {
+                let mut columns = Vec::with_capacity(Self::LENGTH);
+                $(
+                    let mut column = $name::columns();
+                    column.resize($name::LENGTH, ColumnMeta::default());
+                    columns.append(&mut column);
+                )+
+                columns
             }
         }
     };
@@ -299,21 +463,53 @@ tuple_table! { A B C }
 tuple_table! { A B C D}
 tuple_table! { A B C D E}
 tuple_table! { A B C D E F}
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
 
 macro_rules! default_table {
     ( $t:ty ) => {
+        default_table!($t, stringify!($t));
+    };
+    ( $t:ty, $header:expr ) => {
+        default_table!($t, $header, |value: &$t| format!("{}", value));
+    };
+    ( $t:ty, $header:expr, $display:expr ) => {
         impl Tabled for $t {
-            fn fields(&self) -> Vec<String> {
-                vec![format!("{}", self)]
+            const LENGTH: usize = 1;
+
+            fn fields(&self) -> Vec<Cow<'_, str>> {
+                vec![Cow::Owned(($display)(self))]
             }
-            fn headers() -> Vec<String> {
-                vec![stringify!($t).to_string()]
+            fn headers() -> Vec<Cow<'static, str>> {
+                vec![Cow::Borrowed($header)]
             }
         }
     };
 }
 
-default_table!(&str);
+// strings are borrowed rather than formatted
+macro_rules! str_table {
+    ( $t:ty, $header:expr ) => {
+        impl Tabled for $t {
+            const LENGTH: usize = 1;
+
+            fn fields(&self) -> Vec<Cow<'_, str>> {
+                vec![Cow::Borrowed(self.as_ref())]
+            }
+            fn headers() -> Vec<Cow<'static, str>> {
+                vec![Cow::Borrowed($header)]
+            }
+        }
+    };
+}
+
+str_table!(&str, "&str");
+str_table!(String, "String");
+str_table!(Cow<'_, str>, "Cow<str>");
 
 default_table!(char);
 
@@ -336,3 +532,120 @@ default_table!(i128);
 
 default_table!(f32);
 default_table!(f64);
+
+default_table!(Duration, "Duration", |value: &Duration| format!(
+    "{:?}",
+    value
+));
+
+default_table!(IpAddr);
+default_table!(Ipv4Addr);
+default_table!(Ipv6Addr);
+default_table!(SocketAddr);
+
+default_table!(PathBuf, "PathBuf", |path: &PathBuf| format!(
+    "{}",
+    path.display()
+));
+
+macro_rules! pointer_table {
+    ( $pointer:ident ) => {
+        impl<T: Tabled> Tabled for $pointer<T> {
+            const LENGTH: usize = T::LENGTH;
+
+            fn fields(&self) -> Vec<Cow<'_, str>> {
+                T::fields(self)
+            }
+            fn headers() -> Vec<Cow<'static, str>> {
+                T::headers()
+            }
+            fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+                T::fields_into(self, buf)
+            }
+            fn columns() -> Vec<ColumnMeta> {
+                T::columns()
+            }
+        }
+    };
+}
+
+pointer_table!(Box);
+pointer_table!(Rc);
+pointer_table!(Arc);
+
+/// `None` is rendered as a row of empty cells.
+impl<T: Tabled> Tabled for Option<T> {
+    const LENGTH: usize = T::LENGTH;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        let mut fields = Vec::with_capacity(Self::LENGTH);
+        self.fields_into(&mut fields);
+        fields
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        T::headers()
+    }
+    fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+        match self {
+            Some(value) => value.fields_into(buf),
+            None => buf.extend((0..T::LENGTH).map(|_| Cow::Borrowed(""))),
+        }
+    }
+    fn columns() -> Vec<ColumnMeta> {
+        T::columns()
+    }
+}
+
+/// Columns of `T` are followed by columns of `E`,
+/// the ones which are not relevant for a value are empty.
+impl<T: Tabled, E: Tabled> Tabled for Result<T, E> {
+    const LENGTH: usize = T::LENGTH + E::LENGTH;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        let mut fields = Vec::with_capacity(Self::LENGTH);
+        self.fields_into(&mut fields);
+        fields
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        <(T, E)>::headers()
+    }
+    fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+        match self {
+            Ok(value) => {
+                value.fields_into(buf);
+                buf.extend((0..E::LENGTH).map(|_| Cow::Borrowed("")));
+            }
+            Err(err) => {
+                buf.extend((0..T::LENGTH).map(|_| Cow::Borrowed("")));
+                err.fields_into(buf);
+            }
+        }
+    }
+    fn columns() -> Vec<ColumnMeta> {
+        <(T, E)>::columns()
+    }
+}
+
+/// An array is a row which combines its elements in the same way as a tuple.
+impl<T: Tabled, const N: usize> Tabled for [T; N] {
+    const LENGTH: usize = T::LENGTH * N;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        let mut fields = Vec::with_capacity(Self::LENGTH);
+        self.fields_into(&mut fields);
+        fields
+    }
+    fn headers() -> Vec<Cow<'static, str>> {
+        (0..N).flat_map(|_| T::headers()).collect()
+    }
+    fn fields_into<'a>(&'a self, buf: &mut Vec<Cow<'a, str>>) {
+        for value in self {
+            value.fields_into(buf);
+        }
+    }
+    fn columns() -> Vec<ColumnMeta> {
+        let mut columns = T::columns();
+        columns.resize(T::LENGTH, ColumnMeta::default());
+        (0..N).flat_map(|_| columns.clone()).collect()
+    }
+}
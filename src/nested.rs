@@ -0,0 +1,100 @@
+use papergrid::{Alignment, Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// JoinNested makes tables nested in cells of an object join borders of a table.
+///
+/// A frame of a nested table is removed, so its inner lines are continued by borders of the cell.
+/// It's supposed that a nested table has a frame, like the one built by `#[field(table)]`,
+/// cells which don't look like such a table are kept as they are.
+///
+/// Inner lines join only vertical borders which are drawn,
+/// so with a style like [`Style::psql`](./struct.Style.html#method.psql) they stop at the table edges.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Column, JoinNested, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct Version {
+///         id: &'static str,
+///     }
+///
+///     #[derive(Tabled)]
+///     struct Package {
+///         name: &'static str,
+///         #[field(table)]
+///         versions: Vec<Version>,
+///     }
+///
+///     let packages = vec![Package {
+///         name: "tabled",
+///         versions: vec![Version { id: "0.1.0" }, Version { id: "0.1.1" }],
+///     }];
+///
+///     let table = table!(&packages, Style::default(), JoinNested(Column(1..2)));
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             "+--------+----------+\n",
+///             "|  name  | versions |\n",
+///             "+--------+----------+\n",
+///             "| tabled |  id      |\n",
+///             "|        +----------+\n",
+///             "|        | 0.1.0    |\n",
+///             "|        +----------+\n",
+///             "|        | 0.1.1    |\n",
+///             "+--------+----------+\n",
+///         )
+///     );
+/// ```
+#[derive(Debug)]
+pub struct JoinNested<O: Object>(pub O);
+
+impl<O: Object> TableOption for JoinNested<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid) {
+            let content = grid.get_cell_content(row, column).to_owned();
+            let lines = content.lines().collect::<Vec<_>>();
+            if lines.len() < 3 {
+                continue;
+            }
+
+            // a vertical frame of a nested table is expected on each line which isn't a split line
+            let frame = match lines[1].chars().next() {
+                Some(frame) => frame,
+                None => continue,
+            };
+
+            // a line index is a position in the resulting text, so every line must be pushed
+            let mut text = Vec::with_capacity(lines.len() - 2);
+            for s in &lines[1..lines.len() - 1] {
+                let line = text.len();
+                let mut chars = s.chars();
+                let (left, right) = match (chars.next(), chars.next_back()) {
+                    (Some(left), Some(right)) => (left, right),
+                    _ => {
+                        text.push("");
+                        continue;
+                    }
+                };
+
+                if left != frame {
+                    grid.set_joint(row, column, line, left, right);
+                }
+
+                text.push(chars.as_str());
+            }
+
+            grid.set(
+                Entity::Cell(row, column),
+                Settings::new()
+                    .text(text.join("\n"))
+                    .ident(0, 0, 0, 0)
+                    .alignment(Alignment::Left),
+            );
+        }
+    }
+}
@@ -1,19 +1,20 @@
-use std::{
-    collections::BTreeSet,
-    ops::{Bound, RangeBounds},
-};
+use std::ops::{Bound, RangeBounds, RangeFull};
+
+use papergrid::Grid;
+
+use crate::CellSet;
 
 /// Object helps to locate a nessesary part of a `Grid`.
 pub trait Object: Sized {
     /// Cells returns a set of cordinates of cells
-    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)>;
+    fn cells(&self, grid: &Grid) -> CellSet;
 
     /// And combines output of self with rhs object
     fn and<O: Object>(self, rhs: O) -> Combination<Self, O> {
         Combination {
             lhs: self,
             rhs,
-            combinator: combine_cells,
+            combinator: CellSet::union,
         }
     }
 
@@ -22,7 +23,25 @@ pub trait Object: Sized {
         Combination {
             lhs: self,
             rhs,
-            combinator: remove_cells,
+            combinator: CellSet::difference,
+        }
+    }
+
+    /// Intersect keeps only cells which are present in both self and rhs outputs
+    fn intersect<O: Object>(self, rhs: O) -> Combination<Self, O> {
+        Combination {
+            lhs: self,
+            rhs,
+            combinator: CellSet::intersection,
+        }
+    }
+
+    /// Xor keeps only cells which are present either in self or in rhs output but not in both
+    fn xor<O: Object>(self, rhs: O) -> Combination<Self, O> {
+        Combination {
+            lhs: self,
+            rhs,
+            combinator: CellSet::symmetric_difference,
         }
     }
 }
@@ -31,8 +50,8 @@ pub trait Object: Sized {
 pub struct Head;
 
 impl Object for Head {
-    fn cells(&self, _: usize, count_columns: usize) -> Vec<(usize, usize)> {
-        (0..count_columns).map(|column| (0, column)).collect()
+    fn cells(&self, grid: &Grid) -> CellSet {
+        Row(..1).cells(grid)
     }
 }
 
@@ -40,15 +59,8 @@ impl Object for Head {
 pub struct Full;
 
 impl Object for Full {
-    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
-        (0..count_rows)
-            .map(|row| {
-                (0..count_columns)
-                    .map(|column| (row, column))
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>()
-            .concat()
+    fn cells(&self, grid: &Grid) -> CellSet {
+        Row(..).cells(grid)
     }
 }
 
@@ -56,13 +68,12 @@ impl Object for Full {
 pub struct Row<R: RangeBounds<usize>>(pub R);
 
 impl<R: RangeBounds<usize>> Object for Row<R> {
-    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
-        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), count_rows);
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), grid.count_rows());
 
-        (x..y)
-            .map(|row| (0..count_columns).map(|column| (row, column)).collect())
-            .collect::<Vec<Vec<_>>>()
-            .concat()
+        let mut cells = CellSet::empty(grid);
+        cells.insert_segment(x..y, 0..grid.count_columns());
+        cells
     }
 }
 
@@ -70,13 +81,229 @@ impl<R: RangeBounds<usize>> Object for Row<R> {
 pub struct Column<R: RangeBounds<usize>>(pub R);
 
 impl<R: RangeBounds<usize>> Object for Column<R> {
-    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
-        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), count_columns);
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let (x, y) = bounds_to_usize(
+            self.0.start_bound(),
+            self.0.end_bound(),
+            grid.count_columns(),
+        );
+
+        let mut cells = CellSet::empty(grid);
+        cells.insert_segment(0..grid.count_rows(), x..y);
+        cells
+    }
+}
+
+impl Column<RangeFull> {
+    /// Locates the last column.
+    pub fn last() -> LastColumns {
+        LastColumns(1)
+    }
+
+    /// Locates a column by its header.
+    ///
+    /// ```rust
+    ///     use tabled::{table, Column, Format};
+    ///
+    ///     let data = vec![("Grodno", 356_900)];
+    ///
+    ///     let table = table!(&data, Format(Column::by_name("i32"), |s| format!("~{}", s)));
+    ///
+    ///     assert_eq!(
+    ///         table,
+    ///         "+--------+---------+\n\
+    ///          |  &str  |  ~i32   |\n\
+    ///          +--------+---------+\n\
+    ///          | Grodno | ~356900 |\n\
+    ///          +--------+---------+\n"
+    ///     );
+    /// ```
+    pub fn by_name<S: Into<String>>(name: S) -> ColumnNames {
+        ColumnNames(vec![name.into()])
+    }
+
+    /// Locates a set of columns by their headers.
+    pub fn by_names<I, S>(names: I) -> ColumnNames
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ColumnNames(names.into_iter().map(Into::into).collect())
+    }
+}
+
+/// ColumnNames denotes columns which header is one of the given names.
+///
+/// Columns are resolved by a header row at the moment of usage,
+/// so the selection is not affected by adding or reordering fields.
+/// It's constructed by [`Column::by_name`] and [`Column::by_names`].
+#[derive(Debug, Clone)]
+pub struct ColumnNames(Vec<String>);
+
+impl ColumnNames {
+    pub(crate) fn columns(&self, grid: &Grid) -> Vec<usize> {
+        let mut columns = Vec::new();
+        if grid.count_rows() == 0 {
+            return columns;
+        }
+
+        for column in 0..grid.count_columns() {
+            let header = grid.get_cell_content(0, column);
+            if self.0.iter().any(|name| name == header) {
+                columns.push(column);
+            }
+        }
+
+        columns
+    }
+}
+
+impl Object for ColumnNames {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = CellSet::empty(grid);
+        for column in self.columns(grid) {
+            cells.insert_segment(0..grid.count_rows(), column..column + 1);
+        }
+
+        cells
+    }
+}
+
+impl Row<RangeFull> {
+    /// Locates the last row.
+    pub fn last() -> LastRows {
+        LastRows(1)
+    }
+}
+
+/// LastRows denotes a given number of rows at the end of a `Grid`
+pub struct LastRows(pub usize);
+
+impl Object for LastRows {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let count_rows = grid.count_rows();
+        Row(count_rows.saturating_sub(self.0)..).cells(grid)
+    }
+}
+
+/// LastColumns denotes a given number of columns at the end of a `Grid`
+pub struct LastColumns(pub usize);
+
+impl Object for LastColumns {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let count_columns = grid.count_columns();
+        Column(count_columns.saturating_sub(self.0)..).cells(grid)
+    }
+}
+
+/// Body denotes all cells except a header
+pub struct Body;
+
+impl Object for Body {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        Row(1..).cells(grid)
+    }
+}
+
+/// Frame denotes cells on the edges of a `Grid`
+pub struct Frame;
+
+impl Object for Frame {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        Row(..1)
+            .and(LastRows(1))
+            .and(Column(..1))
+            .and(LastColumns(1))
+            .cells(grid)
+    }
+}
+
+/// Segment denotes a rectangle of cells on given rows and columns
+///
+/// ```rust
+///     use tabled::{table, Format, Segment};
+///
+///     let data = vec![(1, 2, 3), (4, 5, 6)];
+///
+///     let table = table!(&data, Format(Segment(1.., 1..), |s| format!("{}0", s)));
+///
+///     assert_eq!(
+///         table,
+///         "+-----+-----+-----+\n\
+///          | i32 | i32 | i32 |\n\
+///          +-----+-----+-----+\n\
+///          |  1  | 20  | 30  |\n\
+///          +-----+-----+-----+\n\
+///          |  4  | 50  | 60  |\n\
+///          +-----+-----+-----+\n"
+///     );
+/// ```
+pub struct Segment<R: RangeBounds<usize>, C: RangeBounds<usize>>(pub R, pub C);
+
+impl<R, C> Object for Segment<R, C>
+where
+    R: RangeBounds<usize>,
+    C: RangeBounds<usize>,
+{
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let (row_start, row_end) =
+            bounds_to_usize(self.0.start_bound(), self.0.end_bound(), grid.count_rows());
+        let (column_start, column_end) = bounds_to_usize(
+            self.1.start_bound(),
+            self.1.end_bound(),
+            grid.count_columns(),
+        );
 
-        (x..y)
-            .map(|column| (0..count_rows).map(|row| (row, column)).collect())
-            .collect::<Vec<Vec<_>>>()
-            .concat()
+        let mut cells = CellSet::empty(grid);
+        cells.insert_segment(row_start..row_end, column_start..column_end);
+        cells
+    }
+}
+
+/// EveryRow denotes each n-th row in a given range.
+///
+/// A step equal to 0 is treated as 1.
+///
+/// ```rust,no_run
+///     # use tabled::{table, EveryRow, Format};
+///     # let data: Vec<&'static str> = Vec::new();
+///     // every other row of a body for a zebra styling
+///     let table = table!(&data, Format(EveryRow(1.., 2), |s| format!("*{}", s)));
+/// ```
+pub struct EveryRow<R: RangeBounds<usize>>(pub R, pub usize);
+
+impl<R: RangeBounds<usize>> Object for EveryRow<R> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), grid.count_rows());
+
+        let mut cells = CellSet::empty(grid);
+        for row in (x..y).step_by(self.1.max(1)) {
+            cells.insert_segment(row..row + 1, 0..grid.count_columns());
+        }
+
+        cells
+    }
+}
+
+/// EveryColumn denotes each n-th column in a given range.
+///
+/// A step equal to 0 is treated as 1.
+pub struct EveryColumn<R: RangeBounds<usize>>(pub R, pub usize);
+
+impl<R: RangeBounds<usize>> Object for EveryColumn<R> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let (x, y) = bounds_to_usize(
+            self.0.start_bound(),
+            self.0.end_bound(),
+            grid.count_columns(),
+        );
+
+        let mut cells = CellSet::empty(grid);
+        for column in (x..y).step_by(self.1.max(1)) {
+            cells.insert_segment(0..grid.count_rows(), column..column + 1);
+        }
+
+        cells
     }
 }
 
@@ -84,12 +311,187 @@ impl<R: RangeBounds<usize>> Object for Column<R> {
 pub struct Cell(pub usize, pub usize);
 
 impl Object for Cell {
-    fn cells(&self, _: usize, _: usize) -> Vec<(usize, usize)> {
-        vec![(self.0, self.1)]
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = CellSet::empty(grid);
+        cells.insert(self.0, self.1);
+        cells
+    }
+}
+
+/// ByContent denotes cells which content satisfies a predicate.
+///
+/// ```rust
+///     use tabled::{table, ByContent, Format};
+///
+///     let data = vec!["OK", "ERR: timeout", "OK"];
+///
+///     let table = table!(&data, Format(ByContent(|s| s.starts_with("ERR")), |s| format!("! {}", s)));
+///
+///     assert_eq!(
+///         table,
+///         "+----------------+\n\
+///          |      &str      |\n\
+///          +----------------+\n\
+///          |       OK       |\n\
+///          +----------------+\n\
+///          | ! ERR: timeout |\n\
+///          +----------------+\n\
+///          |       OK       |\n\
+///          +----------------+\n"
+///     );
+/// ```
+pub struct ByContent<F: Fn(&str) -> bool>(pub F);
+
+impl<F: Fn(&str) -> bool> Object for ByContent<F> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = CellSet::empty(grid);
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                if (self.0)(grid.get_cell_content(row, column)) {
+                    cells.insert(row, column);
+                }
+            }
+        }
+
+        cells
     }
 }
 
-type Combinator = fn(Vec<(usize, usize)>, Vec<(usize, usize)>) -> Vec<(usize, usize)>;
+/// ByPattern denotes cells which content matches a wildcard pattern.
+///
+/// `*` matches any sequence of characters, including an empty one,
+/// and `?` matches a single character. A pattern is matched against the whole content.
+///
+/// ```rust,no_run
+///     # use tabled::{table, ByPattern, Format};
+///     # let data: Vec<&'static str> = Vec::new();
+///     let table = table!(&data, Format(ByPattern("ERR*"), |s| format!("! {}", s)));
+/// ```
+pub struct ByPattern<S: AsRef<str>>(pub S);
+
+impl<S: AsRef<str>> Object for ByPattern<S> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let pattern = self.0.as_ref().chars().collect::<Vec<_>>();
+        ByContent(|s| wildcard_match(&pattern, &s.chars().collect::<Vec<_>>())).cells(grid)
+    }
+}
+
+/// RowsWhere denotes rows which cell on a given column satisfies a predicate.
+///
+/// ```rust
+///     use tabled::{table, Format, RowsWhere};
+///
+///     let data = vec![("Grodno", true), ("Minsk", false)];
+///
+///     let table = table!(&data, Format(RowsWhere(1, |s| s == "false"), |s| format!("-{}-", s)));
+///
+///     assert_eq!(
+///         table,
+///         "+---------+---------+\n\
+///          |  &str   |  bool   |\n\
+///          +---------+---------+\n\
+///          | Grodno  |  true   |\n\
+///          +---------+---------+\n\
+///          | -Minsk- | -false- |\n\
+///          +---------+---------+\n"
+///     );
+/// ```
+pub struct RowsWhere<F: Fn(&str) -> bool>(pub usize, pub F);
+
+impl<F: Fn(&str) -> bool> Object for RowsWhere<F> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = CellSet::empty(grid);
+        if self.0 >= grid.count_columns() {
+            return cells;
+        }
+
+        for row in 0..grid.count_rows() {
+            if (self.1)(grid.get_cell_content(row, self.0)) {
+                cells.insert_segment(row..row + 1, 0..grid.count_columns());
+            }
+        }
+
+        cells
+    }
+}
+
+/// RowsMatching denotes rows which satisfy a predicate.
+///
+/// Unlike [`RowsWhere`] the predicate may inspect a whole row,
+/// looking up cells by a column index or by a header.
+/// The header row is checked as well.
+///
+/// ```rust
+///     use tabled::{table, Format, RowsMatching};
+///
+///     let data = vec![("api", "OK", 12), ("db", "FAILED", 1)];
+///
+///     let failed = RowsMatching(|row| row.get(1) == Some("FAILED") && row.get_by_name("i32") != Some("0"));
+///     let table = table!(&data, Format(failed, |s| format!("!{}", s)));
+///
+///     assert_eq!(
+///         table,
+///         "+------+---------+-----+\n\
+///          | &str |  &str   | i32 |\n\
+///          +------+---------+-----+\n\
+///          | api  |   OK    | 12  |\n\
+///          +------+---------+-----+\n\
+///          | !db  | !FAILED | !1  |\n\
+///          +------+---------+-----+\n"
+///     );
+/// ```
+pub struct RowsMatching<F: Fn(&RowView<'_>) -> bool>(pub F);
+
+impl<F: Fn(&RowView<'_>) -> bool> Object for RowsMatching<F> {
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = CellSet::empty(grid);
+        for row in 0..grid.count_rows() {
+            if (self.0)(&RowView { grid, row }) {
+                cells.insert_segment(row..row + 1, 0..grid.count_columns());
+            }
+        }
+
+        cells
+    }
+}
+
+/// RowView is a row of a `Grid` which is passed to a [`RowsMatching`] predicate
+pub struct RowView<'a> {
+    grid: &'a Grid,
+    row: usize,
+}
+
+impl<'a> RowView<'a> {
+    /// Index returns an index of the row, the header row is 0
+    pub fn index(&self) -> usize {
+        self.row
+    }
+
+    /// Get returns a content of a cell on a given column
+    pub fn get(&self, column: usize) -> Option<&'a str> {
+        if column < self.grid.count_columns() {
+            Some(self.grid.get_cell_content(self.row, column))
+        } else {
+            None
+        }
+    }
+
+    /// GetByName returns a content of a cell on the first column with a given header
+    pub fn get_by_name(&self, header: &str) -> Option<&'a str> {
+        (0..self.grid.count_columns())
+            .find(|&column| self.grid.get_cell_content(0, column) == header)
+            .and_then(|column| self.get(column))
+    }
+
+    /// Cells returns an iterator over the row's contents from left to right
+    pub fn cells(&self) -> impl Iterator<Item = &'a str> + 'a {
+        let grid = self.grid;
+        let row = self.row;
+        (0..grid.count_columns()).map(move |column| grid.get_cell_content(row, column))
+    }
+}
+
+type Combinator = fn(&mut CellSet, &CellSet);
 
 /// Combination struct which allows a chain of objects
 pub struct Combination<L, R> {
@@ -103,41 +505,57 @@ where
     L: Object,
     R: Object,
 {
-    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
-        let l = self.lhs.cells(count_rows, count_columns);
-        let r = self.rhs.cells(count_rows, count_columns);
-        (self.combinator)(l, r)
+    fn cells(&self, grid: &Grid) -> CellSet {
+        let mut cells = self.lhs.cells(grid);
+        (self.combinator)(&mut cells, &self.rhs.cells(grid));
+        cells
     }
 }
 
-fn combine_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
-    lhs.into_iter()
-        .chain(rhs.into_iter())
-        .collect::<BTreeSet<_>>()
-        .into_iter()
-        .collect()
-}
-
-fn remove_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
-    lhs.into_iter().filter(|l| !rhs.contains(l)).collect()
-}
-
 pub(crate) fn bounds_to_usize(
     left: Bound<&usize>,
     right: Bound<&usize>,
     count_elements: usize,
 ) -> (usize, usize) {
-    match (left, right) {
-        (Bound::Included(x), Bound::Included(y)) => (*x, y + 1),
-        (Bound::Included(x), Bound::Excluded(y)) => (*x, *y),
-        (Bound::Included(x), Bound::Unbounded) => (*x, count_elements),
-        (Bound::Unbounded, Bound::Unbounded) => (0, count_elements),
-        (Bound::Unbounded, Bound::Included(y)) => (0, y + 1),
-        (Bound::Unbounded, Bound::Excluded(y)) => (0, *y),
-        (Bound::Excluded(_), Bound::Unbounded)
-        | (Bound::Excluded(_), Bound::Included(_))
-        | (Bound::Excluded(_), Bound::Excluded(_)) => {
-            unreachable!("A start bound can't be excluded")
+    let start = match left {
+        Bound::Included(x) => *x,
+        Bound::Excluded(x) => x.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match right {
+        Bound::Included(y) => y.saturating_add(1),
+        Bound::Excluded(y) => *y,
+        Bound::Unbounded => count_elements,
+    };
+
+    // A range may go beyond a grid or be reversed so we cut it to an existing part
+    let end = end.min(count_elements);
+    let start = start.min(end);
+
+    (start, end)
+}
+
+// The function checks whether a whole text matches a pattern with `*` and `?` wildcards
+fn wildcard_match(pattern: &[char], text: &[char]) -> bool {
+    // position of the last `*` in a pattern and a position in a text it was tried on
+    let mut star = None;
+    let (mut p, mut t) = (0, 0);
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // let the star consume one more char
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
         }
     }
+
+    pattern[p..].iter().all(|c| *c == '*')
 }
@@ -0,0 +1,176 @@
+use std::{cmp::Ordering, iter::FromIterator};
+
+/// Records is a list of typed values which is going to be turned into a table.
+///
+/// It lets sort, filter and limit data by real values before they're converted into strings.
+/// It implements `IntoIterator` so it can be passed to [`table` macros](./macro.table.html) as is.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Records, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct Job {
+///         name: &'static str,
+///         duration: u64,
+///         active: bool,
+///     }
+///
+///     let jobs = vec![
+///         Job { name: "build", duration: 120, active: true },
+///         Job { name: "lint", duration: 9, active: true },
+///         Job { name: "deploy", duration: 60, active: false },
+///         Job { name: "test", duration: 1000, active: true },
+///     ];
+///
+///     let records = Records::new(jobs)
+///         .filter(|job| job.active)
+///         .sort_by_key(|job| job.duration)
+///         .take(2);
+///
+///     let table = table!(records, Style::psql());
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             " name  | duration | active \n",
+///             "-------+----------+--------\n",
+///             " lint  |    9     |  true  \n",
+///             " build |   120    |  true  \n",
+///         )
+///     );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Records<T> {
+    records: Vec<T>,
+}
+
+impl<T> Records<T> {
+    /// New creates a list of records from an iterator
+    pub fn new(iter: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            records: iter.into_iter().collect(),
+        }
+    }
+
+    /// Sort_by sorts records with a comparator function.
+    ///
+    /// The sort is stable.
+    pub fn sort_by<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.records.sort_by(f);
+        self
+    }
+
+    /// Sort_by_key sorts records by a key extracted from a value.
+    ///
+    /// The sort is stable.
+    pub fn sort_by_key<K, F>(mut self, f: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.records.sort_by_key(f);
+        self
+    }
+
+    /// Filter keeps only records for which a predicate returns `true`
+    pub fn filter<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.records.retain(|record| f(record));
+        self
+    }
+
+    /// Take keeps only the first `n` records
+    pub fn take(mut self, n: usize) -> Self {
+        self.records.truncate(n);
+        self
+    }
+
+    /// Skip removes the first `n` records
+    pub fn skip(mut self, n: usize) -> Self {
+        let n = std::cmp::min(n, self.records.len());
+        self.records.drain(..n);
+        self
+    }
+
+    /// Reverse reverses an order of records
+    pub fn reverse(mut self) -> Self {
+        self.records.reverse();
+        self
+    }
+
+    /// Dedup_by removes consecutive records which a function considers equal.
+    ///
+    /// The first record of a series is kept.
+    pub fn dedup_by<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        // `Vec::dedup_by` passes a candidate for removal as a first argument
+        self.records.dedup_by(|next, kept| f(kept, next));
+        self
+    }
+
+    /// Dedup_by_key removes consecutive records which have the same key.
+    ///
+    /// The first record of a series is kept.
+    pub fn dedup_by_key<K, F>(mut self, mut f: F) -> Self
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        self.records.dedup_by_key(|record| f(record));
+        self
+    }
+
+    /// Len returns an amount of records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Is_empty checks whether there's no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Iter returns an iterator over references to records
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.records.iter()
+    }
+}
+
+impl<T> From<Vec<T>> for Records<T> {
+    fn from(records: Vec<T>) -> Self {
+        Self { records }
+    }
+}
+
+impl<T> FromIterator<T> for Records<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<T> IntoIterator for Records<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.records.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Records<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.records.iter()
+    }
+}
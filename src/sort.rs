@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{CellSet, Object, TableOption};
+
+/// Sort reorders rows of a `Grid` by a content of columns.
+///
+/// It's applied to a `Grid` as it is, so all modifications made before (like [`Format`](./struct.Format.html)) are taken into account.
+/// The first row is considered to be a header so it's always kept in place.
+///
+/// Cells are compared in a natural order, which means numbers are compared by their value
+/// so `"10"` goes after `"9"`.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Column, Order, Sort, Style};
+///     let data = vec![("Rust", 2010), ("C", 1972), ("Go", 2009)];
+///     let table = table!(&data, Style::psql(), Sort::new(Column(1..2), Order::Ascending));
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             " &str | i32  \n",
+///             "------+------\n",
+///             "  C   | 1972 \n",
+///             "  Go  | 2009 \n",
+///             " Rust | 2010 \n",
+///         )
+///     );
+/// ```
+///
+/// A custom key can be used in case a natural order isn't suitable.
+///
+/// ```rust,no_run
+///     use tabled::{table, Column, Order, Sort};
+///     # fn parse_size(_: &str) -> f64 { 0.0 }
+///     let data = vec![("disk", "1.5 GiB"), ("tmp", "512 MiB")];
+///     let table = table!(
+///         &data,
+///         Sort::new(Column(1..2), Order::Descending).key(|s| parse_size(s))
+///     );
+/// ```
+pub struct Sort {
+    keys: Vec<SortKey>,
+    indicators: bool,
+}
+
+/// Order represents a direction of sorting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Ascending,
+    Descending,
+}
+
+type ColumnsFn = Box<dyn Fn(&Grid) -> CellSet>;
+type CompareFn = Box<dyn Fn(&str, &str) -> Ordering>;
+
+struct SortKey {
+    columns: ColumnsFn,
+    order: Order,
+    compare: CompareFn,
+}
+
+impl Sort {
+    /// New creates a sorting by columns of an object.
+    ///
+    /// If an object covers a few columns they're used as keys from left to right.
+    pub fn new<O: Object + 'static>(columns: O, order: Order) -> Self {
+        Self {
+            keys: Vec::new(),
+            indicators: false,
+        }
+        .then(columns, order)
+    }
+
+    /// Then adds a key which is used when rows are equal by all previous keys.
+    pub fn then<O: Object + 'static>(mut self, columns: O, order: Order) -> Self {
+        self.keys.push(SortKey {
+            columns: Box::new(move |grid| columns.cells(grid)),
+            order,
+            compare: Box::new(natural_cmp),
+        });
+        self
+    }
+
+    /// Key overrides a comparison of the last added key.
+    ///
+    /// Cells are compared by a value returned from a function instead of a natural order.
+    pub fn key<K, F>(mut self, f: F) -> Self
+    where
+        K: PartialOrd,
+        F: Fn(&str) -> K + 'static,
+    {
+        if let Some(key) = self.keys.last_mut() {
+            key.compare =
+                Box::new(move |lhs, rhs| f(lhs).partial_cmp(&f(rhs)).unwrap_or(Ordering::Equal));
+        }
+
+        self
+    }
+
+    /// Indicators adds a `▲` or `▼` mark to a header of each sorted column.
+    pub fn indicators(mut self) -> Self {
+        self.indicators = true;
+        self
+    }
+
+    fn columns(&self, grid: &Grid) -> Vec<(usize, &SortKey)> {
+        let count_columns = grid.count_columns();
+        let mut columns: Vec<(usize, &SortKey)> = Vec::new();
+        for key in &self.keys {
+            for (_, column) in (key.columns)(grid) {
+                let is_known = columns.iter().any(|(c, _)| *c == column);
+                if column < count_columns && !is_known {
+                    columns.push((column, key));
+                }
+            }
+        }
+
+        columns
+    }
+}
+
+impl TableOption for Sort {
+    fn change(&self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if count_rows == 0 {
+            return;
+        }
+
+        let columns = self.columns(grid);
+
+        // the first row is a header and the last ones may be a footer so they're not involved in sorting
+        let count_body_rows = count_rows - grid.count_footer_rows();
+        let rows = (1..count_body_rows.max(1))
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|(column, _)| grid.get_cell_content(row, *column).to_owned())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut order = (0..rows.len()).collect::<Vec<_>>();
+        order.sort_by(|&lhs, &rhs| {
+            for (i, (_, key)) in columns.iter().enumerate() {
+                let ordering = (key.compare)(&rows[lhs][i], &rows[rhs][i]);
+                let ordering = match key.order {
+                    Order::Ascending => ordering,
+                    Order::Descending => ordering.reverse(),
+                };
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            Ordering::Equal
+        });
+
+        // position[i] is a current place of a row which was originally on i place
+        // origin[i] is an original index of a row which is currently on i place
+        let mut position = (0..rows.len()).collect::<Vec<_>>();
+        let mut origin = position.clone();
+        for (target, source) in order.into_iter().enumerate() {
+            let current = position[source];
+            if current == target {
+                continue;
+            }
+
+            grid.swap_rows(target + 1, current + 1);
+
+            let displaced = origin[target];
+            origin.swap(target, current);
+            position[source] = target;
+            position[displaced] = current;
+        }
+
+        if self.indicators {
+            for (column, key) in columns {
+                let indicator = match key.order {
+                    Order::Ascending => '▲',
+                    Order::Descending => '▼',
+                };
+
+                let header = format!("{} {}", grid.get_cell_content(0, column), indicator);
+                grid.set(Entity::Cell(0, column), Settings::new().text(header));
+            }
+        }
+    }
+}
+
+/// Natural_cmp compares strings treating numbers, including negative and fractional ones, as numbers.
+fn natural_cmp(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.chars().collect::<Vec<_>>();
+    let rhs = rhs.chars().collect::<Vec<_>>();
+
+    let mut i = 0;
+    let mut j = 0;
+    loop {
+        if let (Some((l, next_i)), Some((r, next_j))) = (take_number(&lhs, i), take_number(&rhs, j))
+        {
+            let ordering = cmp_numbers(&l, &r);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            i = next_i;
+            j = next_j;
+            continue;
+        }
+
+        match (lhs.get(i), rhs.get(j)) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => {
+                let ordering = l.cmp(r);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
+
+// Digits are kept without leading zeros in an integer part and trailing zeros in a fractional part
+// so numbers can be compared by length and then lexicographically.
+struct Number {
+    is_negative: bool,
+    integer: String,
+    fraction: String,
+}
+
+// The function parses a number which starts at `start` and returns it with an index right after it.
+//
+// A minus is considered to be a sign only if it's not a part of a word, like in `sda-1` or `2021-06-01`.
+fn take_number(chars: &[char], start: usize) -> Option<(Number, usize)> {
+    let is_digit = |i: usize| chars.get(i).map_or(false, char::is_ascii_digit);
+
+    let mut i = start;
+    let is_negative = chars.get(i) == Some(&'-')
+        && (i == 0 || !chars[i - 1].is_alphanumeric())
+        && is_digit(i + 1);
+    if is_negative {
+        i += 1;
+    }
+
+    if !is_digit(i) {
+        return None;
+    }
+
+    let mut integer = String::new();
+    while is_digit(i) {
+        if !(integer.is_empty() && chars[i] == '0') {
+            integer.push(chars[i]);
+        }
+
+        i += 1;
+    }
+
+    let mut fraction = String::new();
+    if chars.get(i) == Some(&'.') && is_digit(i + 1) {
+        i += 1;
+        while is_digit(i) {
+            fraction.push(chars[i]);
+            i += 1;
+        }
+
+        fraction.truncate(fraction.trim_end_matches('0').len());
+    }
+
+    // -0 is the same as 0
+    let is_negative = is_negative && !(integer.is_empty() && fraction.is_empty());
+
+    let number = Number {
+        is_negative,
+        integer,
+        fraction,
+    };
+
+    Some((number, i))
+}
+
+fn cmp_numbers(lhs: &Number, rhs: &Number) -> Ordering {
+    let magnitude = || {
+        lhs.integer
+            .len()
+            .cmp(&rhs.integer.len())
+            .then_with(|| lhs.integer.cmp(&rhs.integer))
+            .then_with(|| lhs.fraction.cmp(&rhs.fraction))
+    };
+
+    match (lhs.is_negative, rhs.is_negative) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => magnitude(),
+        (true, true) => magnitude().reverse(),
+    }
+}
@@ -42,6 +42,7 @@ impl Style {
     ///     | 3  | Endeavouros  | https://endeavouros.com/  |
     ///     +----+--------------+---------------------------+
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         let line = Line::bordered('-', '+', '+', '+');
 
@@ -196,10 +197,10 @@ impl Style {
 
 #[derive(Debug, Clone, Default)]
 pub struct Line {
-    main: char,
-    intersection: char,
-    left_corner: Option<char>,
-    right_corner: Option<char>,
+    pub(crate) main: char,
+    pub(crate) intersection: char,
+    pub(crate) left_corner: Option<char>,
+    pub(crate) right_corner: Option<char>,
 }
 
 impl Line {
@@ -240,7 +241,8 @@ impl TableOption for Style {
 }
 
 fn make_style(style: &Style, border: &mut Border, is_first_row: bool, is_last_row: bool) {
-    let border = border.empty();
+    // splits of particular columns, like the one of an Index, are kept
+    let border = border.clear_lines();
 
     if is_first_row {
         if let Some(line) = &style.frame.top {
@@ -0,0 +1,57 @@
+use papergrid::{string_width, Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// MaxWidth truncates each line of cells of an object to a given width.
+///
+/// The width is a display width, so wide characters like CJK and emojis take 2 columns.
+///
+/// ```rust
+///     use tabled::{table, Column, MaxWidth};
+///
+///     let data = vec!["Hello World"];
+///
+///     let table = table!(&data, MaxWidth(Column(..), 5));
+///
+///     assert_eq!(
+///         table,
+///         "+-------+\n\
+///          | &str  |\n\
+///          +-------+\n\
+///          | Hello |\n\
+///          +-------+\n"
+///     );
+/// ```
+#[derive(Debug)]
+pub struct MaxWidth<O: Object>(pub O, pub usize);
+
+impl<O: Object> TableOption for MaxWidth<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid) {
+            let content = truncate(grid.get_cell_content(row, column), self.1);
+            grid.set(Entity::Cell(row, column), Settings::new().text(content));
+        }
+    }
+}
+
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    text.lines()
+        .map(|line| truncate_line(line, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// The function cuts a line by the same measure a grid uses for a width of columns
+fn truncate_line(line: &str, width: usize) -> &str {
+    let mut end = 0;
+    for (i, c) in line.char_indices() {
+        let next = i + c.len_utf8();
+        if string_width(&line[..next]) > width {
+            break;
+        }
+
+        end = next;
+    }
+
+    &line[..end]
+}
@@ -0,0 +1,521 @@
+use proc_macro2::Span;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Lit, LitStr, Meta, MetaList, NestedMeta,
+    Token,
+};
+
+use crate::casing::Casing;
+
+// Settings which are set by a `#[tabled(...)]` attribute of a struct or an enum
+pub(crate) struct ContainerAttributes {
+    pub(crate) rename_all: Option<Casing>,
+    pub(crate) columns: Vec<VirtualColumn>,
+    // bounds of an implementation which are used instead of inferred ones
+    pub(crate) bound: Option<Vec<syn::WherePredicate>>,
+}
+
+impl ContainerAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut attrs = Self {
+            rename_all: None,
+            columns: Vec::new(),
+            bound: None,
+        };
+
+        for attr in attributes {
+            if attr.path.is_ident("header") || attr.path.is_ident("field") {
+                return Err(unsupported_macro(attr, "a struct or an enum"));
+            }
+
+            if !attr.path.is_ident("tabled") {
+                continue;
+            }
+
+            for parameter in parse_parameters(attr)? {
+                match &parameter {
+                    NestedMeta::Meta(Meta::NameValue(value))
+                        if value.path.is_ident("rename_all") =>
+                    {
+                        let name = parse_lit_str(&value.lit, "tabled", "rename_all")?;
+                        let casing = Casing::from_name(&name.value()).ok_or_else(|| {
+                            syn::Error::new(
+                                name.span(),
+                                "Unsupported rename_all value, expected one of \
+                                 lowercase, UPPERCASE, camelCase, PascalCase, snake_case, \
+                                 SCREAMING_SNAKE_CASE, kebab-case, Title Case",
+                            )
+                        })?;
+
+                        attrs.rename_all = Some(casing);
+                    }
+                    NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("column") => {
+                        attrs.columns.push(VirtualColumn::parse(list)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("bound") => {
+                        let bound = parse_lit_str(&value.lit, "tabled", "bound")?;
+                        let predicates = bound
+                            .parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)
+                            .map_err(|err| {
+                                syn::Error::new(
+                                    bound.span(),
+                                    format!("Parameter bound for macro tabled should be a list of where predicates, {}", err),
+                                )
+                            })?;
+
+                        attrs.bound = Some(predicates.into_iter().collect());
+                    }
+                    _ => {
+                        return Err(unexpected_parameter(
+                            &parameter,
+                            "tabled",
+                            &["rename_all", "column", "bound"],
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+
+    // The function makes a header from an identifier of a field or a variant
+    pub(crate) fn header_from_ident(&self, ident: &str) -> String {
+        match self.rename_all {
+            Some(casing) => casing.apply(ident),
+            None => ident.to_owned(),
+        }
+    }
+}
+
+// A column which is computed by a method rather than taken from a field
+pub(crate) struct VirtualColumn {
+    pub(crate) name: String,
+    pub(crate) with: syn::Path,
+    // a field after which the column is placed, the column goes last if it's not set
+    pub(crate) after: Option<LitStr>,
+}
+
+impl VirtualColumn {
+    fn parse(list: &MetaList) -> syn::Result<Self> {
+        let mut name = None;
+        let mut with = None;
+        let mut after = None;
+        for parameter in &list.nested {
+            let value = match parameter {
+                NestedMeta::Meta(Meta::NameValue(value)) => value,
+                _ => {
+                    return Err(unexpected_parameter(
+                        parameter,
+                        "column",
+                        &["name", "with", "after"],
+                    ))
+                }
+            };
+
+            if value.path.is_ident("name") {
+                name = Some(parse_lit_str(&value.lit, "column", "name")?.value());
+            } else if value.path.is_ident("with") {
+                with = Some(parse_path(&value.lit, "column", "with")?);
+            } else if value.path.is_ident("after") {
+                after = Some(parse_lit_str(&value.lit, "column", "after")?);
+            } else {
+                return Err(unexpected_parameter(
+                    parameter,
+                    "column",
+                    &["name", "with", "after"],
+                ));
+            }
+        }
+
+        let name = name.ok_or_else(|| {
+            syn::Error::new(
+                list.span(),
+                "Parameter name of column for macro tabled is required",
+            )
+        })?;
+        let with = with.ok_or_else(|| {
+            syn::Error::new(
+                list.span(),
+                "Parameter with of column for macro tabled is required",
+            )
+        })?;
+
+        Ok(Self { name, with, after })
+    }
+}
+
+// Settings which are set by a `#[header(...)]` attribute of a variant
+pub(crate) struct VariantAttributes {
+    pub(crate) name: Option<String>,
+    pub(crate) hidden: bool,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut attrs = Self {
+            name: None,
+            hidden: false,
+        };
+        for attr in attributes {
+            if attr.path.is_ident("field") || attr.path.is_ident("tabled") {
+                return Err(unsupported_macro(attr, "an enum variant"));
+            }
+
+            if !attr.path.is_ident("header") {
+                continue;
+            }
+
+            for parameter in parse_parameters(attr)? {
+                match &parameter {
+                    NestedMeta::Lit(lit) => attrs.name = Some(parse_string(lit, "header", "name")?),
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("name") => {
+                        attrs.name = Some(parse_string(&value.lit, "header", "name")?);
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hidden") => {
+                        attrs.hidden = true
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("hidden") => {
+                        attrs.hidden = parse_bool(&value.lit, "header", "hidden")?;
+                    }
+                    _ => {
+                        return Err(unexpected_parameter(
+                            &parameter,
+                            "header",
+                            &["name", "hidden"],
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+// A place where a field is declared, fields of enum variants support less settings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum FieldKind {
+    Struct,
+    Variant,
+}
+
+// Settings which are set by `#[header(...)]`, `#[field(...)]` and `#[tabled(...)]` attributes of a field
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    pub(crate) name: Option<String>,
+    pub(crate) hidden: bool,
+    pub(crate) inline: Option<String>,
+    pub(crate) table: bool,
+    // a function which returns a style of a nested table
+    pub(crate) table_style: Option<syn::Path>,
+    pub(crate) display_with: Option<DisplayWith>,
+    pub(crate) align: Option<Align>,
+    pub(crate) max_width: Option<usize>,
+    pub(crate) order: Option<(usize, Span)>,
+}
+
+impl FieldAttributes {
+    pub(crate) fn parse(attributes: &[Attribute], kind: FieldKind) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in attributes {
+            let parse = if attr.path.is_ident("header") {
+                Self::parse_header
+            } else if attr.path.is_ident("field") {
+                Self::parse_field
+            } else if attr.path.is_ident("tabled") {
+                if kind == FieldKind::Variant {
+                    return Err(unsupported_macro(attr, "a field of an enum variant"));
+                }
+
+                Self::parse_tabled
+            } else {
+                continue;
+            };
+
+            for parameter in parse_parameters(attr)? {
+                parse(&mut attrs, &parameter, kind)?;
+            }
+        }
+
+        Ok(attrs)
+    }
+
+    fn parse_header(&mut self, parameter: &NestedMeta, kind: FieldKind) -> syn::Result<()> {
+        match parameter {
+            NestedMeta::Lit(lit) => self.name = Some(parse_string(lit, "header", "name")?),
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("name") => {
+                self.name = Some(parse_string(&value.lit, "header", "name")?);
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hidden") => self.hidden = true,
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("hidden") => {
+                self.hidden = parse_bool(&value.lit, "header", "hidden")?;
+            }
+            NestedMeta::Meta(Meta::Path(path))
+                if path.is_ident("inline") && kind == FieldKind::Struct =>
+            {
+                self.inline = Some(String::new());
+            }
+            NestedMeta::Meta(Meta::List(list))
+                if list.path.is_ident("inline") && kind == FieldKind::Struct =>
+            {
+                let prefix = match (list.nested.first(), list.nested.len()) {
+                    (Some(NestedMeta::Lit(lit)), 1) => parse_string(lit, "header", "inline")?,
+                    _ => {
+                        return Err(syn::Error::new(
+                            list.span(),
+                            "Parameter inline for macro header should have a prefix string",
+                        ))
+                    }
+                };
+
+                self.inline = Some(prefix);
+            }
+            _ => {
+                let expected: &[&str] = match kind {
+                    FieldKind::Struct => &["name", "hidden", "inline"],
+                    FieldKind::Variant => &["name", "hidden"],
+                };
+
+                return Err(unexpected_parameter(parameter, "header", expected));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_field(&mut self, parameter: &NestedMeta, kind: FieldKind) -> syn::Result<()> {
+        match parameter {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("table") => self.table = true,
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("table") => {
+                self.table = parse_bool(&value.lit, "field", "table")?;
+            }
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("table") => {
+                self.table = true;
+                for parameter in &list.nested {
+                    match parameter {
+                        NestedMeta::Meta(Meta::NameValue(value))
+                            if value.path.is_ident("style") =>
+                        {
+                            self.table_style = Some(parse_path(&value.lit, "table", "style")?);
+                        }
+                        _ => return Err(unexpected_parameter(parameter, "table", &["style"])),
+                    }
+                }
+            }
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("display_with") => {
+                let function = parse_path(&value.lit, "field", "display_with")?;
+                self.display_with = Some(DisplayWith {
+                    function,
+                    args: vec![DisplayWithArg::Field],
+                });
+            }
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("display_with") => {
+                self.display_with = Some(DisplayWith::parse(list)?);
+            }
+            NestedMeta::Meta(Meta::NameValue(value))
+                if value.path.is_ident("align") && kind == FieldKind::Struct =>
+            {
+                let align = parse_lit_str(&value.lit, "field", "align")?;
+                let align = match align.value().as_str() {
+                    "left" => Align::Left,
+                    "right" => Align::Right,
+                    "center" => Align::Center,
+                    _ => {
+                        return Err(syn::Error::new(
+                            align.span(),
+                            "Unsupported align value, expected one of left, right, center",
+                        ))
+                    }
+                };
+
+                self.align = Some(align);
+            }
+            NestedMeta::Meta(Meta::NameValue(value))
+                if value.path.is_ident("max_width") && kind == FieldKind::Struct =>
+            {
+                self.max_width = Some(parse_usize(&value.lit, "field", "max_width")?);
+            }
+            _ => {
+                let expected: &[&str] = match kind {
+                    FieldKind::Struct => &["table", "display_with", "align", "max_width"],
+                    FieldKind::Variant => &["table", "display_with"],
+                };
+
+                return Err(unexpected_parameter(parameter, "field", expected));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_tabled(&mut self, parameter: &NestedMeta, _: FieldKind) -> syn::Result<()> {
+        match parameter {
+            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("order") => {
+                let order = parse_usize(&value.lit, "tabled", "order")?;
+                self.order = Some((order, value.lit.span()));
+            }
+            _ => return Err(unexpected_parameter(parameter, "tabled", &["order"])),
+        }
+
+        Ok(())
+    }
+}
+
+// An alignment of a column set by `#[field(align = "...")]`
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+// A function which displays a field set by a `display_with` attribute
+pub(crate) struct DisplayWith {
+    pub(crate) function: syn::Path,
+    pub(crate) args: Vec<DisplayWithArg>,
+}
+
+pub(crate) enum DisplayWithArg {
+    // a reference to a field
+    Field,
+    // a reference to a whole struct or enum, which is set by `self`
+    Record,
+    Lit(Lit),
+}
+
+impl DisplayWith {
+    // The function parses `display_with("path", args...)` attribute.
+    //
+    // By default a reference to a field is passed to a function,
+    // in case `self` is among arguments a reference to the record is passed on its place instead.
+    fn parse(list: &MetaList) -> syn::Result<Self> {
+        let mut nested = list.nested.iter();
+        let function = match nested.next() {
+            Some(NestedMeta::Lit(lit)) => parse_path(lit, "field", "display_with")?,
+            _ => {
+                return Err(syn::Error::new(
+                    list.span(),
+                    "Parameter display_with for macro field should start with a function path",
+                ))
+            }
+        };
+
+        let mut args = nested
+            .map(|arg| match arg {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("self") => {
+                    Ok(DisplayWithArg::Record)
+                }
+                NestedMeta::Lit(lit) => Ok(DisplayWithArg::Lit(lit.clone())),
+                _ => Err(syn::Error::new(
+                    arg.span(),
+                    "Arguments of display_with for macro field should be literals or self",
+                )),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let is_record = args.iter().any(|arg| matches!(arg, DisplayWithArg::Record));
+        if !is_record {
+            args.insert(0, DisplayWithArg::Field);
+        }
+
+        Ok(Self { function, args })
+    }
+}
+
+// The function returns parameters of an attribute in a form `#[method(parameters...)]`
+fn parse_parameters(attr: &Attribute) -> syn::Result<Vec<NestedMeta>> {
+    match attr.parse_meta()? {
+        Meta::List(list) => Ok(list.nested.into_iter().collect()),
+        meta => {
+            let method = attr
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            Err(syn::Error::new(
+                meta.span(),
+                format!("Expected parameters for macro {macro} in a form #[{macro}(...)]", macro = method),
+            ))
+        }
+    }
+}
+
+fn unexpected_parameter(parameter: &NestedMeta, method: &str, expected: &[&str]) -> syn::Error {
+    syn::Error::new(
+        parameter.span(),
+        format!(
+            "Unexpected parameter for macro {macro}, expected one of {expected}",
+            macro = method,
+            expected = expected.join(", ")
+        ),
+    )
+}
+
+fn unsupported_macro(attr: &Attribute, place: &str) -> syn::Error {
+    let method = attr
+        .path
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default();
+    syn::Error::new(
+        attr.span(),
+        format!("Macro {macro} can't be used on {place}", macro = method, place = place),
+    )
+}
+
+fn parse_lit_str(lit: &Lit, method: &str, name: &str) -> syn::Result<LitStr> {
+    match lit {
+        Lit::Str(value) => Ok(value.clone()),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("Parameter {name} for macro {macro} should be a string", name = name, macro = method),
+        )),
+    }
+}
+
+// The function parses a string or a byte string
+fn parse_string(lit: &Lit, method: &str, name: &str) -> syn::Result<String> {
+    match lit {
+        Lit::Str(value) => Ok(value.value()),
+        Lit::ByteStr(value) => String::from_utf8(value.value()).map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                format!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro = method, name = name),
+            )
+        }),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("Parameter {name} for macro {macro} should be a string", name = name, macro = method),
+        )),
+    }
+}
+
+fn parse_bool(lit: &Lit, method: &str, name: &str) -> syn::Result<bool> {
+    match lit {
+        Lit::Bool(value) => Ok(value.value),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("Parameter {name} for macro {macro} should be a bool value", name = name, macro = method),
+        )),
+    }
+}
+
+fn parse_usize(lit: &Lit, method: &str, name: &str) -> syn::Result<usize> {
+    match lit {
+        Lit::Int(value) => value.base10_parse(),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("Parameter {name} for macro {macro} should be a positive integer", name = name, macro = method),
+        )),
+    }
+}
+
+fn parse_path(lit: &Lit, method: &str, name: &str) -> syn::Result<syn::Path> {
+    let value = parse_lit_str(lit, method, name)?;
+    value.parse().map_err(|_| {
+        syn::Error::new(
+            value.span(),
+            format!("Parameter {name} for macro {macro} should be a path to a function", name = name, macro = method),
+        )
+    })
+}
@@ -0,0 +1,108 @@
+/// Casing is a style of headers which are made from identifiers
+#[derive(Debug, Clone, Copy)]
+pub enum Casing {
+    Lower,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Title,
+}
+
+impl Casing {
+    /// From_name returns a casing by a name which is used in a `rename_all` attribute
+    pub fn from_name(name: &str) -> Option<Self> {
+        let casing = match name {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "camelCase" => Self::Camel,
+            "PascalCase" => Self::Pascal,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "Title Case" => Self::Title,
+            _ => return None,
+        };
+
+        Some(casing)
+    }
+
+    /// Apply converts an identifier of a field or a variant
+    pub fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+        match self {
+            Self::Lower => ident.to_lowercase(),
+            Self::Upper => ident.to_uppercase(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Self::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Self::Snake => join(&words, "_", str::to_lowercase),
+            Self::ScreamingSnake => join(&words, "_", str::to_uppercase),
+            Self::Kebab => join(&words, "-", str::to_lowercase),
+            Self::Title => join(&words, " ", capitalize),
+        }
+    }
+}
+
+// The function splits an identifier written in snake_case or PascalCase into words.
+// An abbreviation like `HTTPServer` is considered to be a separate word.
+fn split_words(ident: &str) -> Vec<String> {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let is_next_lower = matches!(chars.get(i + 1), Some(c) if c.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || is_next_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn join(words: &[String], separator: &str, f: impl Fn(&str) -> String) -> String {
+    words
+        .iter()
+        .map(|word| f(word))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -12,296 +12,661 @@
 
 extern crate proc_macro;
 
+mod attributes;
+mod casing;
+
+use attributes::{
+    Align, ContainerAttributes, DisplayWith, DisplayWithArg, FieldAttributes, FieldKind,
+    VariantAttributes, VirtualColumn,
+};
 use proc_macro::TokenStream;
 use quote::*;
-use syn::{
-    parse_macro_input, Attribute, DeriveInput, Field, Lit,
-    Meta, NestedMeta,
-};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Field};
 
-#[proc_macro_derive(Tabled, attributes(header, field))]
+#[proc_macro_derive(Tabled, attributes(header, field, tabled))]
 pub fn tabled(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     impl_tabled(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
 
-fn impl_tabled(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_tabled(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &ast.ident;
-    let headers = get_headers(&ast.data);
-    let fields = get_fields(&ast.data);
+    let attrs = ContainerAttributes::parse(&ast.attrs)?;
+    let Methods {
+        length,
+        headers,
+        fields,
+        columns,
+    } = match &ast.data {
+        syn::Data::Struct(st) => get_st_methods(st, &attrs)?,
+        syn::Data::Enum(e) => get_enum_methods(e, &attrs)?,
+        syn::Data::Union(u) => {
+            return Err(syn::Error::new(
+                u.union_token.span(),
+                "Tabled can't be derived for a union",
+            ))
+        }
+    };
 
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let bounds = match &attrs.bound {
+        Some(bound) => bound.clone(),
+        None => get_bounds(&ast.data, &ast.generics)?,
+    };
+
+    let mut generics = ast.generics.clone();
+    generics.make_where_clause().predicates.extend(bounds);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let expanded = quote! {
         impl #impl_generics Tabled for #name #ty_generics #where_clause {
-            fn fields(&self) -> Vec<String> {
-                #fields
+            const LENGTH: usize = #length;
+
+            fn fields(&self) -> Vec<::std::borrow::Cow<'_, str>> {
+                let mut fields = Vec::with_capacity(<Self as Tabled>::LENGTH);
+                self.fields_into(&mut fields);
+                fields
             }
 
-            fn headers() -> Vec<String> {
-                vec![#(String::from(#headers),)*]
+            fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
+                let mut headers = Vec::with_capacity(<Self as Tabled>::LENGTH);
+                #headers
+                headers
             }
+
+            fn fields_into<'__tabled>(&'__tabled self, fields: &mut Vec<::std::borrow::Cow<'__tabled, str>>) {
+                #fields
+            }
+
+            #columns
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
+}
+
+// Parts of an implementation of `Tabled` which are made from a struct or an enum.
+//
+// `headers`, `fields` and `columns` are statements which push values
+// to `headers`, `fields` and `columns` variables accordingly.
+struct Methods {
+    length: proc_macro2::TokenStream,
+    headers: proc_macro2::TokenStream,
+    fields: proc_macro2::TokenStream,
+    columns: proc_macro2::TokenStream,
+}
+
+// Statements of a single column or a group of columns of an inlined field
+struct ColumnParts {
+    length: proc_macro2::TokenStream,
+    header: proc_macro2::TokenStream,
+    field: proc_macro2::TokenStream,
+    column: proc_macro2::TokenStream,
+}
+
+impl VirtualColumn {
+    fn parts(&self) -> ColumnParts {
+        let name = &self.name;
+        let with = &self.with;
+        ColumnParts {
+            length: quote! { 1 },
+            header: quote! { headers.push(::std::borrow::Cow::Borrowed(#name)); },
+            field: quote! { fields.push(::std::borrow::Cow::Owned(format!("{}", #with(self)))); },
+            column: quote! { columns.push(tabled::ColumnMeta::new()); },
+        }
+    }
+}
+
+// A field together with its position and settings
+struct FieldInfo<'a> {
+    index: usize,
+    field: &'a Field,
+    attrs: FieldAttributes,
 }
 
-fn get_headers(d: &syn::Data) -> Vec<String> {
-    match d {
-        syn::Data::Struct(st) => get_st_headers(st),
-        syn::Data::Enum(e) => get_enum_headers(e),
-        syn::Data::Union(_) => todo!("it's not clear how to handle union type"),
+impl FieldInfo<'_> {
+    fn name(&self) -> String {
+        self.field
+            .ident
+            .as_ref()
+            .map_or_else(|| self.index.to_string(), |ident| ident.to_string())
     }
 }
 
-fn get_st_headers(st: &syn::DataStruct) -> Vec<String> {
-    get_fields_headers(st.fields.iter())
+// The function parses settings of all fields and returns the ones which are not hidden
+fn get_visible_fields(fields: &syn::Fields, kind: FieldKind) -> syn::Result<Vec<FieldInfo<'_>>> {
+    let mut visible = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = FieldAttributes::parse(&field.attrs, kind)?;
+        if !attrs.hidden {
+            visible.push(FieldInfo {
+                index,
+                field,
+                attrs,
+            });
+        }
+    }
+
+    Ok(visible)
 }
 
-fn get_fields_headers<'a>(fields: impl Iterator<Item = &'a Field>) -> Vec<String> {
-    fields
-        .enumerate()
-        .filter(|(_, f)| {
-            let is_ignored = find_bool_attribute(&f.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
-        .map(|(i, f)| {
-            let override_name = find_name_attribute(&f.attrs, "header", "name", true);
-            match override_name {
-                Some(name) => name,
-                None => f
-                    .ident
-                    .as_ref()
-                    .map_or_else(|| format!("{}", i), |f| f.to_string()),
+// The function makes methods of `Tabled` for a struct
+fn get_st_methods(st: &syn::DataStruct, attrs: &ContainerAttributes) -> syn::Result<Methods> {
+    let fields = get_visible_fields(&st.fields, FieldKind::Struct)?;
+    let order = get_columns_order(&fields)?;
+
+    for column in &attrs.columns {
+        if let Some(after) = &column.after {
+            if !fields.iter().any(|f| f.name() == after.value()) {
+                return Err(syn::Error::new(
+                    after.span(),
+                    format!(
+                        "Parameter after of column {:?} refers to an unknown or hidden field",
+                        column.name
+                    ),
+                ));
             }
-        })
-        .collect()
-}
+        }
+    }
 
-fn get_enum_headers(e: &syn::DataEnum) -> Vec<String> {
-    e.variants
+    let parts = fields
         .iter()
-        .filter(|v| {
-            let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
-        .map(|v| {
-            let override_name = find_name_attribute(&v.attrs, "header", "name", true);
-            match override_name {
-                Some(name) => vec![name],
-                None => {
-                    let variant = v.ident.to_string();
-                    vec![format!("{}", variant)]
-                }
+        .map(|f| (f.name(), get_field_parts(f, attrs)))
+        .collect();
+    let parts = order_columns(parts, &order);
+    let parts = insert_virtual_columns(parts, &attrs.columns);
+
+    let lengths = parts.iter().map(|part| &part.length);
+    let headers = parts.iter().map(|part| &part.header);
+    let values = parts.iter().map(|part| &part.field);
+    let columns = parts.iter().map(|part| &part.column);
+
+    Ok(Methods {
+        length: quote! { 0 #(+ #lengths)* },
+        headers: quote! { #(#headers)* },
+        fields: quote! { #(#values)* },
+        columns: quote! {
+            fn columns() -> Vec<tabled::ColumnMeta> {
+                let mut columns = Vec::with_capacity(<Self as Tabled>::LENGTH);
+                #(#columns)*
+                columns
             }
-        })
-        .collect::<Vec<Vec<_>>>()
-        .concat()
+        },
+    })
 }
 
-fn get_fields(d: &syn::Data) -> proc_macro2::TokenStream {
-    match d {
-        syn::Data::Struct(st) => {
-            let fields = get_st_fields(st);
-            quote! { vec![#(format!("{}", #fields),)*] }
+// The function makes statements which produce a column of a field or columns of an inlined one
+fn get_field_parts(f: &FieldInfo, attrs: &ContainerAttributes) -> ColumnParts {
+    let value = match &f.field.ident {
+        Some(ident) => quote!(self.#ident),
+        None => {
+            let index = syn::Index::from(f.index);
+            quote!(self.#index)
         }
-        syn::Data::Enum(e) => get_enum_fields(e),
-        syn::Data::Union(_) => todo!(),
+    };
+
+    if let Some(prefix) = &f.attrs.inline {
+        let ty = option_inner_type(&f.field.ty).unwrap_or(&f.field.ty);
+        let header = if prefix.is_empty() {
+            quote! { headers.extend(<#ty as Tabled>::headers()); }
+        } else {
+            quote! {
+                headers.extend(
+                    <#ty as Tabled>::headers()
+                        .into_iter()
+                        .map(|header| ::std::borrow::Cow::Owned(format!("{}{}", #prefix, header))),
+                );
+            }
+        };
+
+        let field = if option_inner_type(&f.field.ty).is_some() {
+            quote! {
+                match &#value {
+                    Some(value) => <#ty as Tabled>::fields_into(value, fields),
+                    None => fields.extend((0..<#ty as Tabled>::LENGTH).map(|_| ::std::borrow::Cow::Borrowed(""))),
+                }
+            }
+        } else {
+            quote! { <#ty as Tabled>::fields_into(&#value, fields); }
+        };
+
+        let column = quote! {
+            let mut inlined = <#ty as Tabled>::columns();
+            inlined.resize(<#ty as Tabled>::LENGTH, tabled::ColumnMeta::new());
+            columns.append(&mut inlined);
+        };
+
+        return ColumnParts {
+            length: quote! { <#ty as Tabled>::LENGTH },
+            header,
+            field,
+            column,
+        };
+    }
+
+    let header = match (&f.attrs.name, &f.field.ident) {
+        (Some(name), _) => name.clone(),
+        (None, Some(ident)) => attrs.header_from_ident(&ident.to_string()),
+        (None, None) => f.index.to_string(),
+    };
+
+    let value = get_field_value(f, value);
+
+    ColumnParts {
+        length: quote! { 1 },
+        header: quote! { headers.push(::std::borrow::Cow::Borrowed(#header)); },
+        field: quote! { fields.push(#value); },
+        column: get_field_column(f),
     }
 }
 
-fn get_st_fields(st: &syn::DataStruct) -> Vec<proc_macro2::TokenStream> {
-    let mut v = Vec::new();
-    for (i, field) in st.fields.iter().enumerate() {
-        let is_ignored = find_bool_attribute(&field.attrs, "header", "hidden");
-        if is_ignored == Some(true) {
-            continue;
-        }
+// The function makes a statement which pushes settings of a column of a field
+fn get_field_column(f: &FieldInfo) -> proc_macro2::TokenStream {
+    let mut column = quote! { tabled::ColumnMeta::new() };
+    if let Some(align) = f.attrs.align {
+        let alignment = match align {
+            Align::Left => quote! { tabled::Alignment::Left },
+            Align::Right => quote! { tabled::Alignment::Right },
+            Align::Center => quote! { tabled::Alignment::Center },
+        };
+
+        column.extend(quote! { .alignment(#alignment) });
+    }
+
+    if let Some(width) = f.attrs.max_width {
+        column.extend(quote! { .max_width(#width) });
+    }
 
-        let mut value = field.ident.as_ref().map_or_else(
-            || {
-                let mut s = quote!(self.);
-                s.extend(syn::Index::from(i).to_token_stream());
-                s
+    quote! { columns.push(#column); }
+}
+
+// The function makes a `Cow<str>` expression which displays a `value` of a field.
+//
+// A value of a string type is borrowed rather than formatted.
+fn get_field_value(f: &FieldInfo, mut value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if f.attrs.table {
+        let style = match &f.attrs.table_style {
+            Some(style) => quote! { #style() },
+            None => quote! { tabled::Style::default() },
+        };
+
+        value = quote! { tabled::table!(&#value, #style).trim_end_matches('\n') };
+    } else if let Some(DisplayWith { function, args }) = &f.attrs.display_with {
+        let args = args.iter().map(|arg| match arg {
+            DisplayWithArg::Field => quote! { &#value },
+            DisplayWithArg::Record => quote! { self },
+            DisplayWithArg::Lit(lit) => quote! { #lit },
+        });
+
+        value = quote! { #function(#(#args),*) };
+    } else if is_str_type(&f.field.ty) {
+        return quote! { ::std::borrow::Cow::Borrowed(::std::convert::AsRef::<str>::as_ref(&#value)) };
+    }
+
+    quote! { ::std::borrow::Cow::Owned(format!("{}", #value)) }
+}
+
+// The function checks whether a type is `&str`, `String` or `Cow<str>`
+fn is_str_type(ty: &syn::Type) -> bool {
+    let is_str = |ty: &syn::Type| matches!(ty, syn::Type::Path(path) if path.path.is_ident("str"));
+    match ty {
+        syn::Type::Reference(reference) => is_str(&reference.elem),
+        syn::Type::Path(path) => match path.path.segments.last() {
+            Some(segment) if segment.ident == "String" => segment.arguments.is_empty(),
+            Some(segment) if segment.ident == "Cow" => match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .any(|arg| matches!(arg, syn::GenericArgument::Type(ty) if is_str(ty))),
+                _ => false,
             },
-            |f| quote!(self.#f),
-        );
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// The function returns positions of fields in a list of columns set by `#[tabled(order = N)]` attributes.
+// Columns without an order fill the rest positions as they are declared.
+fn get_columns_order(fields: &[FieldInfo]) -> syn::Result<Vec<usize>> {
+    let count_columns = fields.len();
+    let mut slots = vec![None; count_columns];
+    let mut rest = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        match field.attrs.order {
+            Some((order, span)) if order >= count_columns => {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "Order {} is out of range, there are {} columns",
+                        order, count_columns
+                    ),
+                ))
+            }
+            Some((order, span)) if slots[order].is_some() => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Order {} is used more than once", order),
+                ))
+            }
+            Some((order, _)) => slots[order] = Some(i),
+            None => rest.push(i),
+        }
+    }
+
+    let mut rest = rest.into_iter();
+    let order = slots
+        .into_iter()
+        .map(|slot| {
+            slot.or_else(|| rest.next())
+                .expect("an amount of columns must be equal to an amount of positions")
+        })
+        .collect();
+
+    Ok(order)
+}
+
+fn order_columns<T>(columns: Vec<T>, order: &[usize]) -> Vec<T> {
+    let mut columns = columns.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .iter()
+        .map(|&i| columns[i].take().expect("a column must be used once"))
+        .collect()
+}
 
-        let with_function = find_name_attribute(&field.attrs, "field", "display_with", false);
-        if let Some(function) = with_function {
-            let function = syn::Ident::new(&function, proc_macro2::Span::call_site());
-            value = quote! { #function(&#value) };
+// The function places virtual columns after fields they refer to or at the end
+fn insert_virtual_columns(
+    columns: Vec<(String, ColumnParts)>,
+    virtual_columns: &[VirtualColumn],
+) -> Vec<ColumnParts> {
+    let mut result = Vec::new();
+    for (key, column) in columns {
+        result.push(column);
+        for column in virtual_columns {
+            if matches!(&column.after, Some(after) if after.value() == key) {
+                result.push(column.parts());
+            }
         }
+    }
 
-        v.push(value);
+    for column in virtual_columns.iter().filter(|c| c.after.is_none()) {
+        result.push(column.parts());
     }
 
-    v
+    result
 }
 
-fn get_enum_fields(e: &syn::DataEnum) -> proc_macro2::TokenStream {
-    let mut fields_per_variant = Vec::new();
-    let mut variant_field_shift = Vec::new();
-    let mut variant_fields_len = Vec::new();
-    let mut count_fields = 0;
-    let variants = e.variants.iter().filter(|v| {
-        let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-        is_ignored != Some(true)
+// The function makes methods of `Tabled` for an enum,
+// enums use a default `columns` method as their fields are different from variant to variant
+fn get_enum_methods(e: &syn::DataEnum, attrs: &ContainerAttributes) -> syn::Result<Methods> {
+    if let Some(after) = attrs
+        .columns
+        .iter()
+        .find_map(|column| column.after.as_ref())
+    {
+        return Err(syn::Error::new(
+            after.span(),
+            "Parameter after of column for macro tabled is not supported for enums",
+        ));
+    }
+
+    let mut variants = Vec::new();
+    for v in &e.variants {
+        let variant_attrs = VariantAttributes::parse(&v.attrs)?;
+        let fields = get_visible_fields(&v.fields, FieldKind::Variant)?;
+        if !variant_attrs.hidden {
+            let pattern = get_variant_pattern(v, &fields);
+            let columns = get_variant_columns(v, &variant_attrs, &fields, attrs);
+            variants.push((pattern, columns));
+        }
+    }
+
+    let mut headers = Vec::new();
+    for (_, columns) in &variants {
+        for (header, _) in columns {
+            if !headers.contains(header) {
+                headers.push(header.clone());
+            }
+        }
+    }
+
+    let arms = variants.iter().map(|(pattern, columns)| {
+        let (columns, values): (Vec<_>, Vec<_>) = columns
+            .iter()
+            .map(|(header, value)| {
+                let column = headers
+                    .iter()
+                    .position(|h| h == header)
+                    .expect("a header of a variant must be in a list of headers");
+                (column, value)
+            })
+            .unzip();
+
+        quote! { Self::#pattern => { #(fields[start + #columns] = #values;)* } }
     });
-    for _ in variants {
-        let fields = vec![quote! { "+".to_string() }];
 
-        variant_field_shift.push(count_fields);
-        variant_fields_len.push(fields.len());
-        count_fields += fields.len();
-        fields_per_variant.push(fields);
+    // hidden variants are not displayed at all
+    let rest_arm = if variants.len() != e.variants.len() || e.variants.is_empty() {
+        quote! {
+            _ => {
+                fields.truncate(start);
+                return;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let count_headers = headers.len();
+    let virtual_columns = attrs
+        .columns
+        .iter()
+        .map(VirtualColumn::parts)
+        .collect::<Vec<_>>();
+    let count_virtual_columns = virtual_columns.len();
+    let virtual_headers = virtual_columns.iter().map(|part| &part.header);
+    let virtual_fields = virtual_columns.iter().map(|part| &part.field);
+
+    let headers = quote! {
+        #(headers.push(::std::borrow::Cow::Borrowed(#headers));)*
+        #(#virtual_headers)*
+    };
+
+    let fields = quote! {
+        let start = fields.len();
+        fields.extend((0..#count_headers).map(|_| ::std::borrow::Cow::Borrowed("")));
+        match self {
+            #(#arms)*
+            #rest_arm
+        }
+
+        #(#virtual_fields)*
+    };
+
+    Ok(Methods {
+        length: quote! { #count_headers + #count_virtual_columns },
+        headers,
+        fields,
+        columns: quote! {},
+    })
+}
+
+// The function returns headers of columns of a variant and expressions which display them.
+//
+// Fields of a variant are its columns, a unit variant has a single column with a `+` mark.
+// A tuple variant with a single field uses a name of the variant as a header.
+fn get_variant_columns(
+    v: &syn::Variant,
+    variant_attrs: &VariantAttributes,
+    fields: &[FieldInfo],
+    attrs: &ContainerAttributes,
+) -> Vec<(String, proc_macro2::TokenStream)> {
+    let variant_name = variant_attrs
+        .name
+        .clone()
+        .unwrap_or_else(|| attrs.header_from_ident(&v.ident.to_string()));
+
+    if fields.is_empty() {
+        return vec![(variant_name, quote! { ::std::borrow::Cow::Borrowed("+") })];
     }
 
-    let variants = e
-        .variants
+    let count_fields = v.fields.len();
+    fields
         .iter()
-        .filter(|v| {
-            let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
-        .map(|v| {
-            let mut token = proc_macro2::TokenStream::new();
-            token.append_all(v.ident.to_token_stream());
-
-            match &v.fields {
-                syn::Fields::Named(fields) => {
-                    let parameters = fields
-                        .named
-                        .iter()
-                        .map(|f| f.ident.as_ref())
-                        .flatten()
-                        .map(|f| {
-                            quote! { #f,}
-                        })
-                        .collect::<Vec<_>>();
-
-                    syn::token::Brace::default().surround(&mut token, |s| {
-                        s.append_all(parameters);
-                    });
-                }
-                syn::Fields::Unnamed(_) => {
-                    // TODO: "a tuple based struct doesn't implemented; here supposed to be a generated Ident for a tuple"
-                    syn::token::Paren::default().surround(&mut token, |s| {
-                        s.append_all(quote! {_});
-                    });
-                }
-                syn::Fields::Unit => {}
+        .map(|f| {
+            let header = match (&f.attrs.name, &f.field.ident) {
+                (Some(name), _) => name.clone(),
+                // a renamed variant prefixes headers of its named fields as it does for unnamed ones
+                (None, Some(ident)) if variant_attrs.name.is_some() => format!(
+                    "{}.{}",
+                    variant_name,
+                    attrs.header_from_ident(&ident.to_string())
+                ),
+                (None, Some(ident)) => attrs.header_from_ident(&ident.to_string()),
+                (None, None) if count_fields == 1 => variant_name.clone(),
+                (None, None) => format!("{}.{}", variant_name, f.index),
             };
 
-            token
+            let binding = variant_field_binding(f.index);
+            (header, get_field_value(f, quote! { (*#binding) }))
         })
-        .collect::<Vec<_>>();
-
-    quote! {
-        let size = #count_fields;
-        let mut v: Vec<String> = std::iter::repeat(String::new()).take(size).collect();
-        #[allow(unused_variables)]
-        match &self {
-            #(Self::#variants => {
-                let fields = vec![#(#fields_per_variant.to_string()),*];
+        .collect()
+}
 
-                for i in #variant_field_shift..#variant_field_shift+#variant_fields_len {
-                    v[i] = fields[i-#variant_field_shift].clone();
+fn get_variant_pattern(v: &syn::Variant, fields: &[FieldInfo]) -> proc_macro2::TokenStream {
+    let ident = &v.ident;
+    match &v.fields {
+        syn::Fields::Named(_) => {
+            let bindings = fields.iter().map(|f| {
+                let name = &f.field.ident;
+                let binding = variant_field_binding(f.index);
+                quote! { #name: #binding }
+            });
+
+            quote! { #ident { #(#bindings,)* .. } }
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let bindings = (0..unnamed.unnamed.len()).map(|i| {
+                if fields.iter().any(|f| f.index == i) {
+                    let binding = variant_field_binding(i);
+                    quote! { #binding }
+                } else {
+                    quote! { _ }
                 }
+            });
 
-                v
-            },)*
-            _ => vec![],
+            quote! { #ident(#(#bindings),*) }
         }
+        syn::Fields::Unit => quote! { #ident },
     }
 }
 
-fn parse_name_attribute(attr: &Attribute, method: &str, name: &str, flaged: bool) -> Option<String> {
-    if attr.path.is_ident(method) {
-        let meta = &attr.parse_meta();
-        match meta {
-            Ok(Meta::List(meta_list)) => {
-                if meta_list.path.is_ident(method) {
-                    for nested_meta in &meta_list.nested {
-                        match nested_meta {
-                            NestedMeta::Lit(Lit::Str(value)) if flaged => return Some(value.value()),
-                            NestedMeta::Lit(Lit::ByteStr(value)) if flaged => return Some(
-                                std::str::from_utf8(&value.value())
-                                    .expect(&format!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
-                                    .to_owned(),
-                            ),
-                            NestedMeta::Meta(Meta::NameValue(value)) => {
-                                if value.path.is_ident(name) {
-                                    match &value.lit {
-                                        Lit::Str(value) => return Some(value.value()),
-                                        Lit::ByteStr(value) => return Some(
-                                            std::str::from_utf8(&value.value())
-                                                .expect(&format!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
-                                                .to_owned(),
-                                        ),
-                                        _ => panic!("Parameter {name} for macro {macro} should be String", name=name, macro=method)
-
-                                    }
-                                }
-                            }
-                            _ => {
-                                
-                            }
-                        }
-                    }
-                }
+fn variant_field_binding(i: usize) -> syn::Ident {
+    format_ident!("field_{}", i)
+}
+
+// The function infers bounds for types of fields which use type parameters,
+// displayed fields must implement `Display` and inlined ones `Tabled`.
+// Type parameters used by a `table` field must implement `Tabled` as they are rows of a nested table.
+//
+// Fields formatted by `display_with` get no bounds since it's a function what decides it.
+fn get_bounds(data: &syn::Data, generics: &syn::Generics) -> syn::Result<Vec<syn::WherePredicate>> {
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        return Ok(Vec::new());
+    }
 
-                None
+    let mut fields = Vec::new();
+    match data {
+        syn::Data::Struct(st) => fields.extend(get_visible_fields(&st.fields, FieldKind::Struct)?),
+        syn::Data::Enum(e) => {
+            for v in &e.variants {
+                if !VariantAttributes::parse(&v.attrs)?.hidden {
+                    fields.extend(get_visible_fields(&v.fields, FieldKind::Variant)?);
+                }
             }
-            _ => None,
         }
-    } else {
-        None
+        syn::Data::Union(_) => {}
     }
-}
 
-fn find_name_attribute(attributes: &[Attribute], method: &str, name: &str, flaged: bool) -> Option<String> {
-    attributes
-        .iter()
-        .find_map(|attr| parse_name_attribute(attr, method, name, flaged))
-}
+    let mut bounds: Vec<syn::WherePredicate> = Vec::new();
+    for f in fields {
+        if f.attrs.display_with.is_some() {
+            continue;
+        }
 
-fn parse_bool_attribute(attr: &Attribute, method: &str, name: &str) -> Option<bool> {
-    if attr.path.is_ident(method) {
-        let meta = &attr.parse_meta();
-        match meta {
-            Ok(Meta::List(meta_list)) => {
-                for nested_meta in &meta_list.nested {
-                    match nested_meta {
-                        NestedMeta::Meta(Meta::Path(path)) => if path.is_ident(name) { return Some(true) } else { return None },
-                        NestedMeta::Meta(Meta::NameValue(value)) => {
-                            if value.path.is_ident(name) {
-                                match &value.lit {
-                                    Lit::Bool(value) => return Some(value.value()),
-                                    Lit::Verbatim(literal) => panic!("{:?}", literal),
-                                    _ => panic!("Parameter {name} for macro {macro} should be a bool value", name=name, macro=method)
-                                }
-                            }
-                        }
-                        _ => {
-                            
-                        }
-                    }
+        if f.attrs.table {
+            for param in &params {
+                if uses_type_params(f.field.ty.to_token_stream(), std::slice::from_ref(param)) {
+                    push_bound(&mut bounds, syn::parse_quote! { #param: Tabled });
                 }
-
-                None
             }
-            _ => None,
+
+            continue;
         }
-    } else {
-        None
+
+        let bound: syn::WherePredicate = if f.attrs.inline.is_some() {
+            let ty = option_inner_type(&f.field.ty).unwrap_or(&f.field.ty);
+            if !uses_type_params(ty.to_token_stream(), &params) {
+                continue;
+            }
+
+            syn::parse_quote! { #ty: Tabled }
+        } else {
+            let ty = &f.field.ty;
+            if !uses_type_params(ty.to_token_stream(), &params) {
+                continue;
+            }
+
+            syn::parse_quote! { #ty: ::std::fmt::Display }
+        };
+
+        push_bound(&mut bounds, bound);
     }
+
+    Ok(bounds)
 }
 
-fn find_bool_attribute(attributes: &[Attribute], method: &str, name: &str) -> Option<bool> {
-    attributes
+// The same type may be used by a few fields so the bound is added only once.
+fn push_bound(bounds: &mut Vec<syn::WherePredicate>, bound: syn::WherePredicate) {
+    let is_new = !bounds
         .iter()
-        .find_map(|attr| parse_bool_attribute(attr, method, name))
+        .any(|b| b.to_token_stream().to_string() == bound.to_token_stream().to_string());
+    if is_new {
+        bounds.push(bound);
+    }
+}
+
+fn uses_type_params(tokens: proc_macro2::TokenStream, params: &[syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => uses_type_params(group.stream(), params),
+        _ => false,
+    })
+}
+
+// The function checks whether a type is `Option<T>` and returns `T` if it is
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let path = match ty {
+        syn::Type::Path(path) => path,
+        _ => return None,
+    };
+
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(syn::GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
 }
@@ -0,0 +1,130 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    table, Alignment, AnsiColor, Color, Colorize, Column, Disable, Full, HorizontalAlignment,
+    Order, RowsMatching, Sort, Style,
+};
+
+#[test]
+fn colorize_keeps_ident_and_width() {
+    let data = vec![("api", "OK"), ("db", "FAILED")];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(Column(..1), Color::new().fg(AnsiColor::Red)),
+        HorizontalAlignment(Full, Alignment::Left),
+    );
+
+    let expected = concat!(
+        "\u{1b}[31m&str\u{1b}[0m|&str  \n",
+        "----+------\n",
+        "\u{1b}[31mapi \u{1b}[0m|OK    \n",
+        "\u{1b}[31mdb  \u{1b}[0m|FAILED\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn colorize_rows_which_failed() {
+    let data = vec![("api", "OK"), ("db", "FAILED")];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(
+            RowsMatching(|row| row.get(1) == Some("FAILED")),
+            Color::new().bg(AnsiColor::Red).bold()
+        ),
+    );
+
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        " api  |   OK   \n",
+        "\u{1b}[1;41m  db  \u{1b}[0m|\u{1b}[1;41m FAILED \u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn colorize_moves_with_sorted_rows() {
+    let data = vec![("api", "OK"), ("db", "FAILED")];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(
+            RowsMatching(|row| row.get(0) == Some("api")),
+            Color::new().underline()
+        ),
+        Sort::new(Column(..1), Order::Descending),
+    );
+
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        "  db  | FAILED \n",
+        "\u{1b}[4m api  \u{1b}[0m|\u{1b}[4m   OK   \u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn colorize_stays_on_column_after_disable() {
+    let data = vec![("api", "OK", "1ms"), ("db", "FAILED", "5s")];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(Column(1..2), Color::new().fg(AnsiColor::Red)),
+        Disable::Column(0..1),
+    );
+
+    let expected = concat!(
+        "\u{1b}[31m  &str  \u{1b}[0m| &str \n",
+        "--------+------\n",
+        "\u{1b}[31m   OK   \u{1b}[0m| 1ms  \n",
+        "\u{1b}[31m FAILED \u{1b}[0m|  5s  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn colorize_empty_table() {
+    let data: Vec<&str> = Vec::new();
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(Column(..), Color::new().fg(AnsiColor::Red)),
+    );
+
+    let expected = concat!("\u{1b}[31m &str \u{1b}[0m\n", "------\n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn colorize_without_cells() {
+    let data = vec!["api"];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Colorize(Column(1..), Color::new().fg(AnsiColor::Red)),
+        Colorize(RowsMatching(|_| false), Color::new().bold()),
+    );
+
+    let expected = concat!(" &str \n", "------\n", " api  \n");
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,226 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use papergrid::{Entity, Grid, Settings};
+use std::borrow::Cow;
+use tabled::{table, Alignment, Column, ColumnMeta, MaxWidth, Row, TableOption, Tabled};
+
+#[derive(Tabled)]
+struct Item {
+    #[field(max_width = 5)]
+    name: &'static str,
+    #[field(align = "right")]
+    price: f32,
+}
+
+#[derive(Tabled)]
+struct Order {
+    #[header(inline("item "))]
+    item: Item,
+    #[field(align = "left")]
+    qty: usize,
+}
+
+#[test]
+fn derive_column_meta() {
+    let items = vec![
+        Item {
+            name: "Keyboard",
+            price: 45.5,
+        },
+        Item {
+            name: "Pen",
+            price: 1.25,
+        },
+    ];
+
+    let expected = concat!(
+        "+-------+-------+\n",
+        "| name  | price |\n",
+        "+-------+-------+\n",
+        "| Keybo |  45.5 |\n",
+        "+-------+-------+\n",
+        "|  Pen  |  1.25 |\n",
+        "+-------+-------+\n",
+    );
+
+    assert_eq!(table!(&items), expected);
+}
+
+#[test]
+fn derive_column_meta_inline() {
+    let orders = vec![Order {
+        item: Item {
+            name: "Keyboard",
+            price: 45.5,
+        },
+        qty: 10,
+    }];
+
+    let expected = concat!(
+        "+-----------+------------+-----+\n",
+        "| item name | item price | qty |\n",
+        "+-----------+------------+-----+\n",
+        "|   Keybo   |       45.5 | 10  |\n",
+        "+-----------+------------+-----+\n",
+    );
+
+    assert_eq!(table!(&orders), expected);
+}
+
+#[test]
+fn tuple_column_meta() {
+    let data = vec![(
+        Item {
+            name: "Pen",
+            price: 1.25,
+        },
+        "red",
+    )];
+
+    let expected = concat!(
+        "+------+-------+------+\n",
+        "| name | price | &str |\n",
+        "+------+-------+------+\n",
+        "| Pen  |  1.25 | red  |\n",
+        "+------+-------+------+\n",
+    );
+
+    assert_eq!(table!(&data), expected);
+}
+
+#[test]
+fn manual_column_meta() {
+    struct Point(i32, i32);
+
+    impl Tabled for Point {
+        const LENGTH: usize = 2;
+
+        fn fields(&self) -> Vec<Cow<'_, str>> {
+            vec![self.0.to_string().into(), self.1.to_string().into()]
+        }
+
+        fn headers() -> Vec<Cow<'static, str>> {
+            vec!["x".into(), "y".into()]
+        }
+
+        fn columns() -> Vec<ColumnMeta> {
+            vec![ColumnMeta::new().alignment(Alignment::Left)]
+        }
+    }
+
+    let expected = concat!(
+        "+------+------+\n",
+        "| x    |  y   |\n",
+        "+------+------+\n",
+        "| 1000 | 2000 |\n",
+        "+------+------+\n",
+    );
+
+    assert_eq!(table!(&[Point(1000, 2000)]), expected);
+}
+
+#[test]
+fn manual_column_meta_hidden() {
+    struct Point(i32, i32);
+
+    impl Tabled for Point {
+        const LENGTH: usize = 2;
+
+        fn fields(&self) -> Vec<Cow<'_, str>> {
+            vec![self.0.to_string().into(), self.1.to_string().into()]
+        }
+
+        fn headers() -> Vec<Cow<'static, str>> {
+            vec!["x".into(), "y".into()]
+        }
+
+        fn columns() -> Vec<ColumnMeta> {
+            vec![ColumnMeta::new(), ColumnMeta::new().hidden()]
+        }
+    }
+
+    let expected = concat!(
+        "+------+\n",
+        "|  x   |\n",
+        "+------+\n",
+        "| 1000 |\n",
+        "+------+\n",
+    );
+
+    assert_eq!(table!(&[Point(1000, 2000)]), expected);
+}
+
+#[test]
+fn column_alignment_keeps_padding() {
+    struct Padding;
+
+    impl TableOption for Padding {
+        fn change(&self, grid: &mut Grid) {
+            grid.set(
+                Entity::Global,
+                Settings::new()
+                    .ident(2, 2, 0, 0)
+                    .alignment(Alignment::Center),
+            );
+        }
+    }
+
+    #[derive(Tabled)]
+    struct Point {
+        #[field(align = "left")]
+        x: i32,
+        y: i32,
+    }
+
+    let expected = concat!(
+        "+--------+--------+\n",
+        "|  x     |   y    |\n",
+        "+--------+--------+\n",
+        "|  1000  |  2000  |\n",
+        "+--------+--------+\n",
+    );
+
+    assert_eq!(table!(&[Point { x: 1000, y: 2000 }], Padding), expected);
+}
+
+#[test]
+fn max_width() {
+    let data = vec![("Hello World", "Multiline\nstring")];
+
+    let expected = concat!(
+        "+-------+-------+\n",
+        "| &str  | &str  |\n",
+        "+-------+-------+\n",
+        "| Hello | Multi |\n",
+        "|       | strin |\n",
+        "+-------+-------+\n",
+    );
+
+    assert_eq!(table!(&data, MaxWidth(Row(1..), 5)), expected);
+    assert_eq!(table!(&data, MaxWidth(Column(..), 5)), expected);
+}
+
+#[test]
+fn max_width_wide_characters() {
+    let data = vec![("你好世界", "🎩🎩🎩")];
+
+    let expected = concat!(
+        "+------+------+\n",
+        "| &str | &str |\n",
+        "+------+------+\n",
+        "| 你好 | 🎩🎩 |\n",
+        "+------+------+\n",
+    );
+
+    assert_eq!(table!(&data, MaxWidth(Row(1..), 5)), expected);
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    build_grid, table, Alignment, Concat, Disable, Full, HorizontalAlignment, Style, TableOption,
+};
+
+#[test]
+fn concat_horizontal() {
+    let data = vec![("C", 1972), ("Rust", 2010)];
+    let authors = vec!["Dennis Ritchie", "Graydon Hoare"];
+    let expected = concat!(
+        "+------+------+----------------+\n",
+        "| &str | i32  |      &str      |\n",
+        "+------+------+----------------+\n",
+        "|  C   | 1972 | Dennis Ritchie |\n",
+        "+------+------+----------------+\n",
+        "| Rust | 2010 | Graydon Hoare  |\n",
+        "+------+------+----------------+\n",
+    );
+
+    let table = table!(
+        &data,
+        Concat::horizontal(build_grid(&authors)),
+        Style::default()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_horizontal_pads_shorter_table() {
+    let data = vec![("C", 1972)];
+    let authors = vec!["Dennis Ritchie", "Brian Kernighan"];
+    let expected = concat!(
+        " &str | i32  |      &str       \n",
+        "------+------+-----------------\n",
+        "  C   | 1972 | Dennis Ritchie  \n",
+        "      |      | Brian Kernighan \n",
+    );
+
+    let table = table!(
+        &data,
+        Concat::horizontal(build_grid(&authors)),
+        Style::psql()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_horizontal_empty_table() {
+    let data = vec![("C", 1972)];
+    let authors: Vec<&str> = Vec::new();
+    let expected = concat!(
+        " &str | i32  | &str \n",
+        "------+------+------\n",
+        "  C   | 1972 |      \n",
+    );
+
+    let table = table!(
+        &data,
+        Concat::horizontal(build_grid(&authors)),
+        Style::psql()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_vertical() {
+    let data = vec![("C", 1972), ("Rust", 2010)];
+    let mut more = build_grid(&[("Zig", 2016)]);
+    Disable::Row(..1).change(&mut more);
+    let expected = concat!(
+        " &str | i32  \n",
+        "------+------\n",
+        "  C   | 1972 \n",
+        " Rust | 2010 \n",
+        " Zig  | 2016 \n",
+    );
+
+    let table = table!(&data, Concat::vertical(more), Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_vertical_pads_narrower_table() {
+    let data = vec![("C", 1972)];
+    let authors = vec!["Dennis Ritchie"];
+    let expected = concat!(
+        "      &str      | i32  \n",
+        "----------------+------\n",
+        "       C        | 1972 \n",
+        "      &str      |      \n",
+        " Dennis Ritchie |      \n",
+    );
+
+    let table = table!(&data, Concat::vertical(build_grid(&authors)), Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_vertical_table_without_rows() {
+    let data = vec![("C", 1972)];
+    let mut empty = build_grid(&[("Zig", 2016)]);
+    Disable::Row(..).change(&mut empty);
+    let expected = concat!(" &str | i32  \n", "------+------\n", "  C   | 1972 \n",);
+
+    let table = table!(&data, Concat::vertical(empty), Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_keeps_styles_of_both_tables() {
+    let data = vec![("C", 1972), ("Rust", 2010)];
+    let mut authors = build_grid(&["Dennis Ritchie", "Graydon Hoare"]);
+    HorizontalAlignment(Full, Alignment::Left).change(&mut authors);
+    let expected = concat!(
+        " &str | i32  |&str          \n",
+        "------+------+--------------\n",
+        "  C   | 1972 |Dennis Ritchie\n",
+        " Rust | 2010 |Graydon Hoare \n",
+    );
+
+    let table = table!(&data, Concat::horizontal(authors), Style::psql());
+
+    assert_eq!(table, expected);
+}
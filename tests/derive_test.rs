@@ -10,6 +10,7 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
+use std::borrow::Cow;
 use tabled::Tabled;
 
 mod structure {
@@ -79,6 +80,7 @@ mod structure {
         assert_eq!(vec!["f3".to_owned()], St::headers());
     }
 
+    #[allow(dead_code)]
     #[test]
     fn unit_structure_hidden_field() {
         #[derive(Tabled)]
@@ -100,7 +102,7 @@ mod structure {
         fn display_option(o: &Option<&'static str>) -> String {
             match o {
                 Some(s) => format!("some {}", s),
-                None => format!("none"),
+                None => "none".to_string(),
             }
         }
 
@@ -126,7 +128,7 @@ mod structure {
         fn display_option(o: &Option<&'static str>) -> String {
             match o {
                 Some(s) => format!("some {}", s),
-                None => format!("none"),
+                None => "none".to_string(),
             }
         }
 
@@ -142,6 +144,7 @@ mod structure {
         assert_eq!(vec!["0".to_owned(), "1".to_owned()], St::headers());
     }
 
+    #[allow(dead_code)]
     #[test]
     fn enum_hidden_variant() {
         #[derive(Tabled)]
@@ -155,12 +158,18 @@ mod structure {
             K,
         }
 
-        assert_eq!(vec!["A".to_owned(), "K".to_owned()], E::headers());
         assert_eq!(
-            vec!["+".to_owned(), "".to_owned()],
+            vec!["a".to_owned(), "b".to_owned(), "K".to_owned()],
+            E::headers()
+        );
+        assert_eq!(
+            vec!["1".to_owned(), "2".to_owned(), "".to_owned()],
             E::A { a: 1, b: 2 }.fields()
         );
-        assert_eq!(vec!["".to_owned(), "+".to_owned()], E::K.fields());
+        assert_eq!(
+            vec!["".to_owned(), "".to_owned(), "+".to_owned()],
+            E::K.fields()
+        );
         assert!(E::B(String::new()).fields().is_empty());
     }
 
@@ -181,7 +190,8 @@ mod structure {
 
         assert_eq!(
             vec![
-                "Variant 1".to_owned(),
+                "Variant 1.a".to_owned(),
+                "Variant 1.b".to_owned(),
                 "Variant 2".to_owned(),
                 "K".to_owned()
             ],
@@ -246,7 +256,9 @@ mod structure {
 
         fn infer_type<T: std::fmt::Display>(v: T) -> (Vec<String>, Vec<String>) {
             let st = St(v);
-            (<St<T> as Tabled>::headers(), st.fields())
+            let headers = <St<T> as Tabled>::headers();
+            let fields = st.fields().into_iter().map(Cow::into_owned).collect();
+            (headers.into_iter().map(Cow::into_owned).collect(), fields)
         }
 
         let (headers, fields) = infer_type(1);
@@ -255,6 +267,7 @@ mod structure {
         assert_eq!(vec!["1".to_owned()], fields);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn enum_structure() {
         #[derive(Tabled)]
@@ -265,20 +278,784 @@ mod structure {
         }
 
         assert_eq!(
-            vec!["A".to_owned(), "B".to_owned(), "K".to_owned()],
+            vec![
+                "a".to_owned(),
+                "b".to_owned(),
+                "B".to_owned(),
+                "K".to_owned()
+            ],
             E::headers()
         );
         assert_eq!(
-            vec!["+".to_owned(), "".to_owned(), "".to_owned()],
+            vec!["1".to_owned(), "2".to_owned(), "".to_owned(), "".to_owned()],
             E::A { a: 1, b: 2 }.fields()
         );
         assert_eq!(
-            vec!["".to_owned(), "".to_owned(), "+".to_owned()],
+            vec!["".to_owned(), "".to_owned(), "".to_owned(), "+".to_owned()],
             E::K.fields()
         );
         assert_eq!(
-            vec!["".to_owned(), "+".to_owned(), "".to_owned()],
-            E::B(String::new()).fields()
+            vec![
+                "".to_owned(),
+                "".to_owned(),
+                "text".to_owned(),
+                "".to_owned()
+            ],
+            E::B(String::from("text")).fields()
+        );
+    }
+
+    #[derive(Tabled)]
+    struct Address {
+        city: &'static str,
+        #[header("zip code")]
+        zip: u32,
+    }
+
+    #[test]
+    fn inline_field() {
+        #[derive(Tabled)]
+        struct St {
+            name: &'static str,
+            #[header(inline)]
+            address: Address,
+        }
+
+        let st = St {
+            name: "Maxim",
+            address: Address {
+                city: "Kharkiv",
+                zip: 61000,
+            },
+        };
+
+        assert_eq!(
+            vec!["name".to_owned(), "city".to_owned(), "zip code".to_owned()],
+            St::headers()
+        );
+        assert_eq!(
+            vec!["Maxim".to_owned(), "Kharkiv".to_owned(), "61000".to_owned()],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn inline_field_with_prefix() {
+        #[derive(Tabled)]
+        struct St(&'static str, #[header(inline("addr."))] Address);
+
+        let st = St(
+            "Maxim",
+            Address {
+                city: "Kharkiv",
+                zip: 61000,
+            },
+        );
+
+        assert_eq!(
+            vec![
+                "0".to_owned(),
+                "addr.city".to_owned(),
+                "addr.zip code".to_owned()
+            ],
+            St::headers()
         );
+        assert_eq!(
+            vec!["Maxim".to_owned(), "Kharkiv".to_owned(), "61000".to_owned()],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn inline_option_field() {
+        #[derive(Tabled)]
+        struct St {
+            #[header(inline("home "))]
+            home: Option<Address>,
+            name: &'static str,
+        }
+
+        let st = St {
+            home: None,
+            name: "Maxim",
+        };
+
+        assert_eq!(
+            vec![
+                "home city".to_owned(),
+                "home zip code".to_owned(),
+                "name".to_owned()
+            ],
+            St::headers()
+        );
+        assert_eq!(
+            vec!["".to_owned(), "".to_owned(), "Maxim".to_owned()],
+            st.fields()
+        );
+
+        let st = St {
+            home: Some(Address {
+                city: "Kharkiv",
+                zip: 61000,
+            }),
+            name: "Maxim",
+        };
+
+        assert_eq!(
+            vec!["Kharkiv".to_owned(), "61000".to_owned(), "Maxim".to_owned()],
+            st.fields()
+        );
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn enum_tuple_variant() {
+        #[derive(Tabled)]
+        enum E {
+            Point(i32, i32),
+            #[header("Value")]
+            Scalar(#[header(hidden)] u8, f32),
+            Unknown,
+        }
+
+        assert_eq!(
+            vec![
+                "Point.0".to_owned(),
+                "Point.1".to_owned(),
+                "Value.1".to_owned(),
+                "Unknown".to_owned()
+            ],
+            E::headers()
+        );
+        assert_eq!(
+            vec![
+                "1".to_owned(),
+                "-2".to_owned(),
+                "".to_owned(),
+                "".to_owned()
+            ],
+            E::Point(1, -2).fields()
+        );
+        assert_eq!(
+            vec![
+                "".to_owned(),
+                "".to_owned(),
+                "0.5".to_owned(),
+                "".to_owned()
+            ],
+            E::Scalar(1, 0.5).fields()
+        );
+    }
+
+    #[test]
+    fn enum_variant_fields_union() {
+        fn display_flag(flag: &bool) -> String {
+            if *flag { "yes" } else { "no" }.to_owned()
+        }
+
+        #[derive(Tabled)]
+        enum Shape {
+            Circle {
+                #[header("x")]
+                center_x: f32,
+                radius: f32,
+            },
+            Rect {
+                x: f32,
+                width: f32,
+                #[field(display_with = "display_flag")]
+                filled: bool,
+            },
+        }
+
+        assert_eq!(
+            vec![
+                "x".to_owned(),
+                "radius".to_owned(),
+                "width".to_owned(),
+                "filled".to_owned()
+            ],
+            Shape::headers()
+        );
+        assert_eq!(
+            vec!["1".to_owned(), "2".to_owned(), "".to_owned(), "".to_owned()],
+            Shape::Circle {
+                center_x: 1.0,
+                radius: 2.0
+            }
+            .fields()
+        );
+        assert_eq!(
+            vec![
+                "3".to_owned(),
+                "".to_owned(),
+                "4".to_owned(),
+                "yes".to_owned()
+            ],
+            Shape::Rect {
+                x: 3.0,
+                width: 4.0,
+                filled: true
+            }
+            .fields()
+        );
+    }
+
+    #[test]
+    fn rename_all_structure_fields() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "Title Case")]
+        struct St {
+            first_name: &'static str,
+            #[header("Surname")]
+            last_name: &'static str,
+            http_port: u16,
+        }
+
+        assert_eq!(
+            vec![
+                "First Name".to_owned(),
+                "Surname".to_owned(),
+                "Http Port".to_owned()
+            ],
+            St::headers()
+        );
+    }
+
+    #[test]
+    fn rename_all_cases() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "camelCase")]
+        struct Camel {
+            first_name: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "kebab-case")]
+        struct Kebab {
+            first_name: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "UPPERCASE")]
+        struct Upper {
+            first_name: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "SCREAMING_SNAKE_CASE")]
+        struct ScreamingSnake(u8);
+
+        assert_eq!(vec!["firstName".to_owned()], Camel::headers());
+        assert_eq!(vec!["first-name".to_owned()], Kebab::headers());
+        assert_eq!(vec!["FIRST_NAME".to_owned()], Upper::headers());
+        assert_eq!(vec!["0".to_owned()], ScreamingSnake::headers());
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn rename_all_enum() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "snake_case")]
+        enum E {
+            HTTPServer {
+                listen_port: u16,
+            },
+            LocalFile(String),
+            #[header("Other")]
+            UnknownKind,
+        }
+
+        assert_eq!(
+            vec![
+                "listen_port".to_owned(),
+                "local_file".to_owned(),
+                "Other".to_owned()
+            ],
+            E::headers()
+        );
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "Title Case")]
+        enum Status {
+            NotStarted,
+            InProgress,
+        }
+
+        assert_eq!(
+            vec!["Not Started".to_owned(), "In Progress".to_owned()],
+            Status::headers()
+        );
+    }
+
+    mod formatters {
+        pub fn bytes(n: &u64) -> String {
+            format!("{} B", n)
+        }
+
+        pub fn precision(n: &f64, precision: usize, suffix: &str) -> String {
+            format!("{:.*}{}", precision, n, suffix)
+        }
+    }
+
+    #[test]
+    fn display_with_path_and_arguments() {
+        #[derive(Tabled)]
+        struct St {
+            #[field(display_with = "formatters::bytes")]
+            size: u64,
+            #[field(display_with("formatters::precision", 2, "%"))]
+            usage: f64,
+        }
+
+        let st = St {
+            size: 1024,
+            usage: 12.3456,
+        };
+
+        assert_eq!(vec!["1024 B".to_owned(), "12.35%".to_owned()], st.fields());
+    }
+
+    #[test]
+    fn display_with_self() {
+        #[derive(Tabled)]
+        struct Payment {
+            #[field(display_with("Self::display_amount", self))]
+            amount: u64,
+            #[header(hidden)]
+            currency: &'static str,
+        }
+
+        impl Payment {
+            fn display_amount(&self) -> String {
+                format!("{} {}", self.amount, self.currency)
+            }
+        }
+
+        let payment = Payment {
+            amount: 100,
+            currency: "EUR",
+        };
+
+        assert_eq!(vec!["amount".to_owned()], Payment::headers());
+        assert_eq!(vec!["100 EUR".to_owned()], payment.fields());
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn display_with_self_and_arguments_in_enum() {
+        fn display_point(p: &Point, separator: &str) -> String {
+            match p {
+                Point::Flat(x, y) => format!("{}{}{}", x, separator, y),
+                Point::Origin => String::new(),
+            }
+        }
+
+        #[derive(Tabled)]
+        enum Point {
+            Flat(
+                #[header("point")]
+                #[field(display_with("display_point", self, ":"))]
+                i32,
+                #[header(hidden)] i32,
+            ),
+            Origin,
+        }
+
+        assert_eq!(
+            vec!["point".to_owned(), "Origin".to_owned()],
+            Point::headers()
+        );
+        assert_eq!(
+            vec!["1:2".to_owned(), "".to_owned()],
+            Point::Flat(1, 2).fields()
+        );
+    }
+
+    #[test]
+    fn virtual_columns() {
+        #[derive(Tabled)]
+        #[tabled(column(name = "Total", with = "Self::total", after = "price"))]
+        #[tabled(column(name = "Discount", with = "discount"))]
+        struct Item {
+            name: &'static str,
+            qty: u32,
+            price: u32,
+            #[header(hidden)]
+            promo: bool,
+        }
+
+        impl Item {
+            fn total(&self) -> u32 {
+                self.qty * self.price
+            }
+        }
+
+        fn discount(item: &Item) -> &'static str {
+            if item.promo {
+                "10%"
+            } else {
+                ""
+            }
+        }
+
+        let item = Item {
+            name: "apple",
+            qty: 3,
+            price: 5,
+            promo: true,
+        };
+
+        assert_eq!(
+            vec![
+                "name".to_owned(),
+                "qty".to_owned(),
+                "price".to_owned(),
+                "Total".to_owned(),
+                "Discount".to_owned()
+            ],
+            Item::headers()
+        );
+        assert_eq!(
+            vec![
+                "apple".to_owned(),
+                "3".to_owned(),
+                "5".to_owned(),
+                "15".to_owned(),
+                "10%".to_owned()
+            ],
+            item.fields()
+        );
+    }
+
+    #[test]
+    fn virtual_columns_in_tuple_struct_and_enum() {
+        #[derive(Tabled)]
+        #[tabled(column(name = "sum", with = "Self::sum", after = "0"))]
+        struct Pair(u8, u8);
+
+        impl Pair {
+            fn sum(&self) -> u8 {
+                self.0 + self.1
+            }
+        }
+
+        assert_eq!(
+            vec!["0".to_owned(), "sum".to_owned(), "1".to_owned()],
+            Pair::headers()
+        );
+        assert_eq!(
+            vec!["1".to_owned(), "3".to_owned(), "2".to_owned()],
+            Pair(1, 2).fields()
+        );
+
+        #[derive(Tabled)]
+        #[tabled(column(name = "code", with = "Self::code"))]
+        enum Status {
+            Active,
+            Failed { reason: &'static str },
+        }
+
+        impl Status {
+            fn code(&self) -> u8 {
+                match self {
+                    Status::Active => 0,
+                    Status::Failed { .. } => 1,
+                }
+            }
+        }
+
+        assert_eq!(
+            vec!["Active".to_owned(), "reason".to_owned(), "code".to_owned()],
+            Status::headers()
+        );
+        assert_eq!(
+            vec!["".to_owned(), "oom".to_owned(), "1".to_owned()],
+            Status::Failed { reason: "oom" }.fields()
+        );
+        assert_eq!(
+            vec!["+".to_owned(), "".to_owned(), "0".to_owned()],
+            Status::Active.fields()
+        );
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn order_fields() {
+        #[derive(Tabled)]
+        struct St {
+            a: u8,
+            #[tabled(order = 0)]
+            b: u8,
+            #[header(hidden)]
+            hidden: u8,
+            c: u8,
+            #[tabled(order = 3)]
+            d: u8,
+        }
+
+        let st = St {
+            a: 1,
+            b: 2,
+            hidden: 0,
+            c: 3,
+            d: 4,
+        };
+
+        assert_eq!(
+            vec![
+                "b".to_owned(),
+                "a".to_owned(),
+                "c".to_owned(),
+                "d".to_owned()
+            ],
+            St::headers()
+        );
+        assert_eq!(
+            vec![
+                "2".to_owned(),
+                "1".to_owned(),
+                "3".to_owned(),
+                "4".to_owned()
+            ],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn order_inline_field() {
+        #[derive(Tabled)]
+        struct St {
+            name: &'static str,
+            #[header(inline)]
+            #[tabled(order = 0)]
+            address: Address,
+        }
+
+        let st = St {
+            name: "Maxim",
+            address: Address {
+                city: "Kharkiv",
+                zip: 61000,
+            },
+        };
+
+        assert_eq!(
+            vec!["city".to_owned(), "zip code".to_owned(), "name".to_owned()],
+            St::headers()
+        );
+        assert_eq!(
+            vec!["Kharkiv".to_owned(), "61000".to_owned(), "Maxim".to_owned()],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn generic_struct() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        struct Pair<T, U> {
+            a: T,
+            b: U,
+            #[header(hidden)]
+            hidden: U,
+        }
+
+        let st = Pair {
+            a: 1,
+            b: String::from("text"),
+            hidden: String::new(),
+        };
+        assert_eq!(
+            vec!["a".to_owned(), "b".to_owned()],
+            Pair::<i32, String>::headers()
+        );
+        assert_eq!(vec!["1".to_owned(), "text".to_owned()], st.fields());
+    }
+
+    #[test]
+    fn generic_struct_with_a_bounded_param() {
+        #[derive(Tabled)]
+        struct Wrapper<T: Clone>(#[header("value")] T);
+
+        let st = Wrapper("a");
+        assert_eq!(vec!["value".to_owned()], Wrapper::<&str>::headers());
+        assert_eq!(vec!["a".to_owned()], st.fields());
+    }
+
+    #[test]
+    fn generic_inline_field() {
+        #[derive(Tabled)]
+        struct St<T> {
+            name: &'static str,
+            #[header(inline)]
+            inner: T,
+        }
+
+        let st = St {
+            name: "Maxim",
+            inner: Address {
+                city: "Kharkiv",
+                zip: 61000,
+            },
+        };
+        assert_eq!(
+            vec!["name".to_owned(), "city".to_owned(), "zip code".to_owned()],
+            St::<Address>::headers()
+        );
+        assert_eq!(
+            vec!["Maxim".to_owned(), "Kharkiv".to_owned(), "61000".to_owned()],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn generic_table_field() {
+        #[derive(Tabled)]
+        struct St<T> {
+            id: u8,
+            #[field(table)]
+            items: Vec<T>,
+        }
+
+        let st = St {
+            id: 1,
+            items: vec![Address {
+                city: "Kharkiv",
+                zip: 61000,
+            }],
+        };
+        assert_eq!(
+            vec!["id".to_owned(), "items".to_owned()],
+            St::<Address>::headers()
+        );
+        assert_eq!(
+            vec![
+                "1".to_owned(),
+                "+---------+----------+\n\
+                 |  city   | zip code |\n\
+                 +---------+----------+\n\
+                 | Kharkiv |  61000   |\n\
+                 +---------+----------+"
+                    .to_owned()
+            ],
+            st.fields()
+        );
+    }
+
+    #[test]
+    fn generic_enum() {
+        #[derive(Tabled)]
+        enum E<T> {
+            A(T),
+            B { value: T },
+        }
+
+        assert_eq!(vec!["A".to_owned(), "value".to_owned()], E::<u8>::headers());
+        assert_eq!(vec!["1".to_owned(), "".to_owned()], E::A(1).fields());
+        assert_eq!(
+            vec!["".to_owned(), "2".to_owned()],
+            E::B { value: 2 }.fields()
+        );
+    }
+
+    #[test]
+    fn generic_struct_with_custom_bound() {
+        fn debug<T: std::fmt::Debug>(value: &T) -> String {
+            format!("{:?}", value)
+        }
+
+        #[derive(Tabled)]
+        #[tabled(bound = "T: std::fmt::Debug")]
+        struct St<T> {
+            #[field(display_with = "debug")]
+            value: T,
+        }
+
+        let st = St { value: "text" };
+        assert_eq!(vec!["value".to_owned()], St::<&str>::headers());
+        assert_eq!(vec!["\"text\"".to_owned()], st.fields());
+    }
+
+    #[test]
+    fn length() {
+        #[derive(Tabled)]
+        #[tabled(column(name = "tag", with = "Self::tag"))]
+        struct St {
+            name: &'static str,
+            #[header(inline)]
+            address: Option<Address>,
+            #[header(hidden)]
+            #[allow(dead_code)]
+            hidden: u8,
+        }
+
+        impl St {
+            fn tag(&self) -> &'static str {
+                "tag"
+            }
+        }
+
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        enum E {
+            A(u8),
+            B {
+                a: u8,
+                b: u8,
+            },
+            #[header(hidden)]
+            C,
+        }
+
+        assert_eq!(St::LENGTH, 4);
+        assert_eq!(St::LENGTH, St::headers().len());
+        assert_eq!(E::LENGTH, 3);
+        assert_eq!(E::LENGTH, E::headers().len());
+    }
+
+    #[test]
+    fn strings_are_borrowed() {
+        #[derive(Tabled)]
+        struct St {
+            a: &'static str,
+            b: String,
+            c: Cow<'static, str>,
+            d: u8,
+        }
+
+        let st = St {
+            a: "a",
+            b: String::from("b"),
+            c: Cow::Borrowed("c"),
+            d: 0,
+        };
+
+        let fields = st.fields();
+        assert_eq!(vec!["a", "b", "c", "0"], fields);
+        assert!(matches!(fields[0], Cow::Borrowed(_)));
+        assert!(matches!(fields[1], Cow::Borrowed(_)));
+        assert!(matches!(fields[2], Cow::Borrowed(_)));
+        assert!(matches!(fields[3], Cow::Owned(_)));
+        assert!(St::headers()
+            .iter()
+            .all(|header| matches!(header, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn fields_into_appends_to_buffer() {
+        #[derive(Tabled)]
+        enum E {
+            A(u8),
+            #[header(hidden)]
+            B,
+        }
+
+        let mut buf = vec![Cow::Borrowed("start")];
+        E::A(1).fields_into(&mut buf);
+        assert_eq!(vec!["start", "1"], buf);
+
+        E::B.fields_into(&mut buf);
+        assert_eq!(vec!["start", "1"], buf);
     }
 }
@@ -11,7 +11,7 @@
 // copies or substantial portions of the Software.
 
 use papergrid::Alignment;
-use tabled::{table, Disable, Full, HorizontalAlignment, Style, Tabled};
+use tabled::{table, Column, Disable, Full, HorizontalAlignment, Style, Tabled};
 
 #[derive(Tabled)]
 struct Linux {
@@ -232,3 +232,51 @@ fn disable_all_table_via_columns() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn disable_columns_by_name() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "destribution\n",
+        "------------\n",
+        "Fedora      \n",
+        "OpenSUSE    \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        HorizontalAlignment(Full, Alignment::Left),
+        Disable::Column(Column::by_names(vec!["link", "id", "unknown"])),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_rows_by_name_keeps_table() {
+    let data = vec![(0, "Fedora")];
+
+    let expected = concat!("i32|&str  \n", "---+------\n", "0  |Fedora\n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        HorizontalAlignment(Full, Alignment::Left),
+        Disable::Row(Column::by_name("i32")),
+    );
+
+    assert_eq!(table, expected);
+}
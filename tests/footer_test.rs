@@ -0,0 +1,306 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{style::Line, table, Aggregate, Column, Footer, Order, Sort, Style};
+
+#[test]
+fn footer_default_style() {
+    let data = vec![("parse", "1.25 ms"), ("io", "n/a"), ("render", "3.5 ms")];
+    let expected = concat!(
+        "+--------+---------+\n",
+        "|  &str  |  &str   |\n",
+        "+--------+---------+\n",
+        "| parse  | 1.25 ms |\n",
+        "+--------+---------+\n",
+        "|   io   |   n/a   |\n",
+        "+--------+---------+\n",
+        "| render | 3.5 ms  |\n",
+        "+--------+---------+\n",
+        "|  Sum   |  4.75   |\n",
+        "+--------+---------+\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::default(),
+        Footer::new()
+            .label("Sum")
+            .aggregate(Column(1..), Aggregate::Sum)
+            .line(Line::bordered('-', '+', '+', '+'))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_without_line() {
+    let data = vec![("parse", "1.25", 1000), ("render", "3.5", 250)];
+    let expected = concat!(
+        "  &str  | &str  | i32  \n",
+        "--------+-------+------\n",
+        " parse  | 1.25  | 1000 \n",
+        " render |  3.5  | 250  \n",
+        "   2    | 2.375 | 250  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .aggregate(Column(..1), Aggregate::Count)
+            .aggregate(Column(1..2), Aggregate::Mean)
+            .aggregate(Column(2..3), Aggregate::Min)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_pseudo_style() {
+    let data = vec![("parse", "1.25 ms"), ("render", "3.5 ms")];
+    let expected = concat!(
+        "┌────────┬─────────┐\n",
+        "│  &str  │  &str   │\n",
+        "├────────┼─────────┤\n",
+        "│ parse  │ 1.25 ms │\n",
+        "│ render │ 3.5 ms  │\n",
+        "╞════════╪═════════╡\n",
+        "│  Max   │  3.50   │\n",
+        "└────────┴─────────┘\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::pseudo_clean(),
+        Footer::new()
+            .label("Max")
+            .aggregate(Column(1..), Aggregate::Max)
+            .line(Line::bordered('═', '╪', '╞', '╡'))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_custom_aggregate() {
+    let data = vec![("parse", 1000), ("render", 250)];
+    let expected = concat!(
+        "  &str  |   i32    \n",
+        "--------+----------\n",
+        " parse  |   1000   \n",
+        " render |   250    \n",
+        "--------+----------\n",
+        "        | 1000+250 \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .aggregate(Column(1..2), Aggregate::custom(|cells| cells.join("+")))
+            .line(Line::short('-', '+'))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_on_empty_table() {
+    let data: Vec<(&str, &str, &str)> = Vec::new();
+    let expected = concat!(
+        "+-------+------+------+\n",
+        "| &str  | &str | &str |\n",
+        "+-------+------+------+\n",
+        "| Total |  0   |      |\n",
+        "+-------+------+------+\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::default(),
+        Footer::new()
+            .label("Total")
+            .aggregate(Column(1..2), Aggregate::Sum)
+            .aggregate(Column(2..3), Aggregate::Mean)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_on_single_column() {
+    let data = vec!["3", "", "4"];
+    let expected = concat!(" &str \n", "------\n", "  3   \n", "      \n", "  4   \n", "  2   \n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .label("Count")
+            .aggregate(Column(..), Aggregate::Count)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_with_label_only() {
+    let data = vec![("a", 1)];
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  1  \n",
+        " end  |     \n",
+    );
+
+    let table = table!(&data, Style::psql(), Footer::new().label("end"));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_skips_dates() {
+    let data = vec![("2021-06-01", "10"), ("2021-07-15", "20")];
+    let expected = concat!(
+        "    &str    | &str \n",
+        "------------+------\n",
+        " 2021-06-01 |  10  \n",
+        " 2021-07-15 |  20  \n",
+        "     0      |  30  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new().aggregate(Column(..), Aggregate::Sum)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_skips_versions() {
+    let data = vec![
+        ("tabled", "v0.3.0"),
+        ("papergrid", "1.2.3"),
+        ("rust", "1.51"),
+    ];
+    let expected = concat!(
+        "   &str    |  &str  \n",
+        "-----------+--------\n",
+        "  tabled   | v0.3.0 \n",
+        " papergrid | 1.2.3  \n",
+        "   rust    |  1.51  \n",
+        "    Max    |  1.51  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .label("Max")
+            .aggregate(Column(1..), Aggregate::Max)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_skips_type_names() {
+    let data = vec![("i32", "u8"), ("Vec<u64>", "f32")];
+    let expected = concat!(
+        "   &str   | &str \n",
+        "----------+------\n",
+        "   i32    |  u8  \n",
+        " Vec<u64> | f32  \n",
+        "          |      \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new().aggregate(Column(..), Aggregate::Max)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_with_currencies_and_units() {
+    let data = vec![("-$5", "10 kg"), ("USD 7.5", "0.5kg"), ("€12", "-.25 kg")];
+    let expected = concat!(
+        "  &str   |  &str   \n",
+        "---------+---------\n",
+        "   -$5   |  10 kg  \n",
+        " USD 7.5 |  0.5kg  \n",
+        "   €12   | -.25 kg \n",
+        "  14.5   |  10.25  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new().aggregate(Column(..), Aggregate::Sum)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_is_kept_last_by_sort() {
+    let data = vec![("b", 3), ("a", 20), ("c", 1)];
+    let expected = concat!(
+        " &str  | i32 \n",
+        "-------+-----\n",
+        "   a   | 20  \n",
+        "   b   |  3  \n",
+        "   c   |  1  \n",
+        " Total | 24  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .label("Total")
+            .aggregate(Column(1..), Aggregate::Sum),
+        Sort::new(Column(1..), Order::Descending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_after_footer() {
+    let data = vec![("a", 2), ("b", 4)];
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  2  \n",
+        "  b   |  4  \n",
+        " Sum  |  6  \n",
+        " Mean |  3  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .label("Sum")
+            .aggregate(Column(1..), Aggregate::Sum),
+        Footer::new()
+            .label("Mean")
+            .aggregate(Column(1..), Aggregate::Mean)
+    );
+
+    assert_eq!(table, expected);
+}
@@ -10,7 +10,11 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use tabled::{multiline, table, Cell, Column, Format, Full, Head, Object, Row, Style, Tabled};
+use papergrid::{Entity, Grid, Settings};
+use tabled::{
+    multiline, table, Alignment, Cell, ChangeRing, Column, Format, FormatCell, Full, Head, Object,
+    Row, Style, TableOption, Tabled,
+};
 
 #[derive(Tabled)]
 struct Linux {
@@ -329,6 +333,136 @@ fn formatting_not_combination_test() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn formatting_cell_with_position_test() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "  id   | destribution |            link             \n",
+        "-------+--------------+-----------------------------\n",
+        " 1:0:0 |    Fedora    |  <https://getfedora.org/>   \n",
+        " 2:0:2 |   OpenSUSE   | <https://www.opensuse.org/> \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        FormatCell(Full.not(Head), |s, cell| match cell.header {
+            "id" => format!("{}:{}:{}", cell.row, cell.column, s),
+            "link" => format!("<{}>", s),
+            _ => s.to_owned(),
+        }),
+        FormatCell(Head, |s, cell| {
+            assert_eq!(s, cell.header);
+            s.to_owned()
+        }),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn change_ring_test() {
+    let data = vec![
+        (0, "Grodno", true),
+        (1, "Minsk", true),
+        (2, "Hamburg", false),
+    ];
+
+    let expected = concat!(
+        " i32 |  &str   | bool  \n",
+        "-----+---------+-------\n",
+        " 0   |  Grodno | true  \n",
+        "   1 | Minsk   |  true \n",
+        " 2   | Hamburg | false \n",
+    );
+
+    let pool = vec![Alignment::Left, Alignment::Right];
+    let table = table!(&data, Style::psql(), ChangeRing::new(Row(1..), pool));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn change_ring_by_columns_test() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let expected = concat!(
+        " [i32] |  &str  | [bool] \n",
+        "-------+--------+--------\n",
+        "  [0]  | Grodno | [true] \n",
+        "  [1]  | Minsk  | [true] \n",
+    );
+
+    let pool = vec![
+        multiline(|s| format!("[{}]", s)),
+        multiline(|s| s.to_owned()),
+    ];
+    let table = table!(
+        &data,
+        Style::psql(),
+        ChangeRing::new(Full, pool).by_columns()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn change_ring_keeps_padding_test() {
+    struct Padding;
+
+    impl TableOption for Padding {
+        fn change(&self, grid: &mut Grid) {
+            grid.set(
+                Entity::Global,
+                Settings::new()
+                    .ident(0, 0, 0, 0)
+                    .alignment(Alignment::Center),
+            );
+        }
+    }
+
+    let data = vec![(0, "Grodno"), (1, "Minsk")];
+
+    let expected = concat!(
+        "i32| &str \n",
+        "---+------\n",
+        "0  |Grodno\n",
+        "1  | Minsk\n",
+    );
+
+    let pool = vec![Alignment::Left, Alignment::Right];
+    let table = table!(
+        &data,
+        Style::psql(),
+        Padding,
+        ChangeRing::new(Row(1..), pool)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn change_ring_with_empty_pool_test() {
+    let data = vec![(0, "Grodno")];
+
+    let pool: Vec<Alignment> = Vec::new();
+    let table = table!(&data, Style::psql(), ChangeRing::new(Full, pool));
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
+
 #[cfg(feature = "color")]
 mod color {
 
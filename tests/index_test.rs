@@ -0,0 +1,184 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use papergrid::{Entity, Grid, Settings};
+use tabled::{table, Aggregate, Alignment, Column, Disable, Footer, Index, Style, TableOption};
+
+#[test]
+fn index_default() {
+    let data = vec![("Mercury", 0), ("Earth", 1)];
+    let expected = concat!(
+        "+---+---------+-----+\n",
+        "|   |  &str   | i32 |\n",
+        "+---+---------+-----+\n",
+        "| 0 | Mercury |  0  |\n",
+        "+---+---------+-----+\n",
+        "| 1 |  Earth  |  1  |\n",
+        "+---+---------+-----+\n",
+    );
+
+    let table = table!(&data, Index::new());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_start_and_header() {
+    let data = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+    let expected = concat!(
+        " #  | &str \n",
+        "----+------\n",
+        " 1  |  a   \n",
+        " 2  |  b   \n",
+        " 3  |  c   \n",
+        " 4  |  d   \n",
+        " 5  |  e   \n",
+        " 6  |  f   \n",
+        " 7  |  g   \n",
+        " 8  |  h   \n",
+        " 9  |  i   \n",
+        " 10 |  j   \n",
+    );
+
+    let table = table!(&data, Style::psql(), Index::new().header("#").start(1));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_labels() {
+    let data = vec!["Mercury", "Earth", "Mars"];
+    let expected = concat!(
+        "      | &str  \n",
+        "------+-------\n",
+        " id-a | Earth \n",
+        " id-b | Mars  \n",
+    );
+
+    let table = table!(
+        &data,
+        Disable::Row(1..2),
+        Style::psql(),
+        Index::new().labels(|i| format!("id-{}", (b'a' + i as u8) as char))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_as_header_column() {
+    let data = vec![("Mercury", 0), ("Earth", 1)];
+    let expected = concat!(
+        "┌────┬─────────┬─────┐\n",
+        "│ no ║  &str   │ i32 │\n",
+        "├────┼─────────┼─────┤\n",
+        "│ 0  ║ Mercury │  0  │\n",
+        "│ 1  ║  Earth  │  1  │\n",
+        "└────┴─────────┴─────┘\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::pseudo_clean(),
+        Index::new()
+            .header("no")
+            .split('║')
+            .alignment(Alignment::Left)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_alignment_keeps_padding() {
+    struct Padding;
+
+    impl TableOption for Padding {
+        fn change(&self, grid: &mut Grid) {
+            grid.set(
+                Entity::Global,
+                Settings::new()
+                    .ident(2, 0, 0, 0)
+                    .alignment(Alignment::Right),
+            );
+        }
+    }
+
+    let expected = concat!(
+        "+----+---------+-----+\n",
+        "|  no|     &str|  i32|\n",
+        "+----+---------+-----+\n",
+        "|  0 |  Mercury|    0|\n",
+        "+----+---------+-----+\n",
+    );
+
+    let data = vec![("Mercury", 0)];
+    let table = table!(
+        &data,
+        Padding,
+        Index::new().header("no").alignment(Alignment::Left)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_on_empty_data() {
+    let expected = concat!(
+        "+------+------+-------+\n",
+        "| rows | &str | usize |\n",
+        "+------+------+-------+\n",
+    );
+
+    let data: Vec<(&str, usize)> = Vec::new();
+    let table = table!(&data, Index::new().header("rows"));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_split_is_kept_by_style() {
+    let data = vec!["Rust", "Go"];
+    let expected = concat!(
+        "   ‖ &str \n",
+        "---+------\n",
+        " 0 ‖ Rust \n",
+        " 1 ‖  Go  \n",
+    );
+
+    let table = table!(&data, Index::new().split('‖'), Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_skips_footer() {
+    let data = vec![("Earth", 1), ("Mars", 2)];
+    let expected = concat!(
+        "   | &str  | i32 \n",
+        "---+-------+-----\n",
+        " 1 | Earth |  1  \n",
+        " 2 | Mars  |  2  \n",
+        "   | Total |  3  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .label("Total")
+            .aggregate(Column(1..), Aggregate::Sum),
+        Index::new().start(1)
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,213 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Column, JoinNested, Style, Tabled};
+
+#[derive(Tabled)]
+struct Item {
+    name: &'static str,
+    qty: usize,
+}
+
+#[derive(Tabled)]
+struct Order {
+    id: usize,
+    #[field(table)]
+    items: Vec<Item>,
+}
+
+#[test]
+fn nested_table() {
+    let data = vec![
+        Order {
+            id: 1,
+            items: vec![
+                Item {
+                    name: "apple",
+                    qty: 3,
+                },
+                Item {
+                    name: "kiwi",
+                    qty: 12,
+                },
+            ],
+        },
+        Order {
+            id: 2,
+            items: vec![],
+        },
+    ];
+    let expected = concat!(
+        "+----+-----------------+\n",
+        "| id |      items      |\n",
+        "+----+-----------------+\n",
+        "| 1  | +-------+-----+ |\n",
+        "|    | | name  | qty | |\n",
+        "|    | +-------+-----+ |\n",
+        "|    | | apple |  3  | |\n",
+        "|    | +-------+-----+ |\n",
+        "|    | | kiwi  | 12  | |\n",
+        "|    | +-------+-----+ |\n",
+        "+----+-----------------+\n",
+        "| 2  | +------+-----+  |\n",
+        "|    | | name | qty |  |\n",
+        "|    | +------+-----+  |\n",
+        "+----+-----------------+\n",
+    );
+
+    let table = table!(&data);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_table_joined() {
+    let data = vec![
+        Order {
+            id: 1,
+            items: vec![
+                Item {
+                    name: "apple",
+                    qty: 3,
+                },
+                Item {
+                    name: "kiwi",
+                    qty: 12,
+                },
+            ],
+        },
+        Order {
+            id: 2,
+            items: vec![],
+        },
+    ];
+    let expected = concat!(
+        "+----+-------------+\n",
+        "| id |    items    |\n",
+        "+----+-------------+\n",
+        "| 1  | name  | qty |\n",
+        "|    +-------+-----+\n",
+        "|    | apple |  3  |\n",
+        "|    +-------+-----+\n",
+        "|    | kiwi  | 12  |\n",
+        "+----+-------------+\n",
+        "| 2  | name | qty  |\n",
+        "+----+-------------+\n",
+    );
+
+    let table = table!(&data, Style::default(), JoinNested(Column(1..2)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_table_joined_in_empty_table() {
+    let data: Vec<Order> = Vec::new();
+    let expected = concat!("+----+-------+\n", "| id | items |\n", "+----+-------+\n",);
+
+    let table = table!(&data, Style::default(), JoinNested(Column(..)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn join_nested_ignores_plain_cells() {
+    let expected = concat!(
+        "+------+-----+\n",
+        "| name | qty |\n",
+        "+------+-----+\n",
+        "| kiwi | 12  |\n",
+        "+------+-----+\n",
+    );
+
+    let data = vec![Item {
+        name: "kiwi",
+        qty: 12,
+    }];
+    let table = table!(&data, Style::default(), JoinNested(Column(..)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_table_joined_without_frame() {
+    let expected = concat!(
+        " id |   items    \n",
+        "----+------------\n",
+        " 1  | name | qty \n",
+        "    +------+-----\n",
+        "    | kiwi | 12  \n",
+    );
+
+    let data = vec![Order {
+        id: 1,
+        items: vec![Item {
+            name: "kiwi",
+            qty: 12,
+        }],
+    }];
+    let table = table!(&data, Style::psql(), JoinNested(Column(1..2)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn join_nested_keeps_empty_lines() {
+    let expected = concat!(
+        "+------+------+\n",
+        "| &str | &str |\n",
+        "+------+------+\n",
+        "| a    |  c   |\n",
+        "|      |      |\n",
+        "+------+      |\n",
+        "| b    |      |\n",
+        "+------+------+\n",
+    );
+
+    let data = vec![("+---+\n| a |\n\n+---+\n| b |\n+---+", "c")];
+    let table = table!(&data, Style::default(), JoinNested(Column(..1)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_table_with_style() {
+    #[derive(Tabled)]
+    struct Basket {
+        id: usize,
+        #[field(table(style = "Style::pseudo_clean"))]
+        items: Vec<Item>,
+    }
+
+    let expected = concat!(
+        "+----+----------------+\n",
+        "| id |     items      |\n",
+        "+----+----------------+\n",
+        "| 1  | ┌──────┬─────┐ |\n",
+        "|    | │ name │ qty │ |\n",
+        "|    | ├──────┼─────┤ |\n",
+        "|    | │ kiwi │ 12  │ |\n",
+        "|    | └──────┴─────┘ |\n",
+        "+----+----------------+\n",
+    );
+
+    let data = vec![Basket {
+        id: 1,
+        items: vec![Item {
+            name: "kiwi",
+            qty: 12,
+        }],
+    }];
+    let table = table!(&data);
+
+    assert_eq!(table, expected);
+}
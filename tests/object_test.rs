@@ -0,0 +1,360 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::ops::Bound;
+
+use tabled::{
+    build_grid, table, Body, ByContent, ByPattern, Cell, CellSet, Column, EveryColumn, EveryRow,
+    Format, Frame, Full, Head, LastColumns, LastRows, Object, Row, RowsMatching, RowsWhere,
+    Segment, Style,
+};
+
+#[test]
+fn by_content() {
+    let grid = build_grid(vec![
+        ("ERR", "timeout"),
+        ("OK", "done"),
+        ("ERROR", "refused"),
+    ]);
+
+    let cells = ByContent(|s| s.starts_with("ERR")).cells(&grid);
+    assert_eq!(cells, vec![(1, 0), (3, 0)]);
+
+    let cells = ByContent(|s| s.len() == 4).cells(&grid);
+    assert_eq!(cells, vec![(0, 0), (0, 1), (2, 1)]);
+}
+
+#[test]
+fn by_content_combined_with_position() {
+    let grid = build_grid(vec![("ERR", "timeout"), ("OK", "done"), ("WARN", "slow")]);
+
+    let cells = ByContent(|s| s.contains('&')).not(Head).cells(&grid);
+    assert!(cells.is_empty());
+
+    let cells = Full.not(Head).not(ByContent(|s| s.len() > 3)).cells(&grid);
+    assert_eq!(cells, vec![(1, 0), (2, 0)]);
+}
+
+#[test]
+fn by_pattern() {
+    let grid = build_grid(vec![
+        ("ERR", "timeout"),
+        ("OK", "done"),
+        ("ERROR", "refused"),
+        ("WARN", "slow"),
+    ]);
+
+    assert_eq!(ByPattern("ERR*").cells(&grid), vec![(1, 0), (3, 0)]);
+    assert_eq!(ByPattern("ERR").cells(&grid), vec![(1, 0)]);
+    assert_eq!(ByPattern("?o??").cells(&grid), vec![(2, 1)]);
+    assert_eq!(
+        ByPattern("*s*").cells(&grid),
+        vec![(0, 0), (0, 1), (3, 1), (4, 1)]
+    );
+    assert_eq!(ByPattern("*e*e*").cells(&grid), vec![(3, 1)]);
+    assert_eq!(ByPattern(String::from("*")).cells(&grid), Full.cells(&grid));
+    assert!(ByPattern("").cells(&grid).is_empty());
+}
+
+#[test]
+fn rows_where() {
+    let grid = build_grid(vec![
+        ("ERR", "timeout"),
+        ("OK", "done"),
+        ("ERROR", "refused"),
+    ]);
+
+    let cells = RowsWhere(0, |s| s == "OK").cells(&grid);
+    assert_eq!(cells, vec![(2, 0), (2, 1)]);
+
+    let cells = RowsWhere(1, |s| s.ends_with('d')).cells(&grid);
+    assert_eq!(cells, vec![(3, 0), (3, 1)]);
+
+    let cells = RowsWhere(2, |_| true).cells(&grid);
+    assert!(cells.is_empty());
+}
+
+#[test]
+fn column_by_name() {
+    let grid = build_grid(vec![("Grodno", 356_900, true)]);
+
+    let cells = Column::by_name("i32").cells(&grid);
+    assert_eq!(cells, vec![(0, 1), (1, 1)]);
+
+    let cells = Column::by_names(vec!["bool", "&str"]).cells(&grid);
+    assert_eq!(cells, vec![(0, 0), (0, 2), (1, 0), (1, 2)]);
+
+    let cells = Column::by_name("u64").cells(&grid);
+    assert!(cells.is_empty());
+
+    let cells = Column::by_name("i32")
+        .and(Column::by_name("bool"))
+        .not(Head)
+        .cells(&grid);
+    assert_eq!(cells, vec![(1, 1), (1, 2)]);
+}
+
+#[test]
+fn objects_on_table_without_rows() {
+    let grid = build_grid(Vec::<(&str, &str)>::new());
+
+    assert_eq!(ByContent(|_| true).cells(&grid), Head.cells(&grid));
+    assert_eq!(
+        RowsWhere(0, |s| s == "&str").cells(&grid),
+        Head.cells(&grid)
+    );
+    assert_eq!(LastRows(1).cells(&grid), Head.cells(&grid));
+    assert!(Body.cells(&grid).is_empty());
+    assert!(EveryRow(1.., 1).cells(&grid).is_empty());
+}
+
+#[test]
+fn objects_on_single_column() {
+    let grid = build_grid(vec!["a", "b"]);
+
+    assert_eq!(Column::last().cells(&grid), Full.cells(&grid));
+    assert_eq!(Frame.cells(&grid), Full.cells(&grid));
+    assert_eq!(ByPattern("?").cells(&grid), Body.cells(&grid));
+    assert!(LastColumns(2).not(Column(..1)).cells(&grid).is_empty());
+}
+
+#[test]
+fn table_with_content_selectors() {
+    let data = vec![
+        ("ERR", "timeout"),
+        ("OK", "done"),
+        ("ERROR", "refused"),
+        ("WARN", "slow"),
+    ];
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Format(ByPattern("ERR*"), |s| format!("!{}", s)),
+        Format(RowsWhere(0, |s| s == "WARN"), |s| s.to_uppercase()),
+    );
+
+    let expected = concat!(
+        "  &str  |  &str   \n",
+        "--------+---------\n",
+        "  !ERR  | timeout \n",
+        "   OK   |  done   \n",
+        " !ERROR | refused \n",
+        "  WARN  |  SLOW   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn last_rows_and_columns() {
+    let grid = build_grid(vec![(1, 2, 3), (4, 5, 6)]);
+
+    assert_eq!(Row::last().cells(&grid), vec![(2, 0), (2, 1), (2, 2)]);
+    assert_eq!(Column::last().cells(&grid), vec![(0, 2), (1, 2), (2, 2)]);
+    assert_eq!(
+        LastColumns(2).not(Head).cells(&grid),
+        vec![(1, 1), (1, 2), (2, 1), (2, 2)]
+    );
+    assert_eq!(LastRows(10).cells(&grid), Full.cells(&grid));
+    assert!(LastRows(0).cells(&grid).is_empty());
+}
+
+#[test]
+fn body_and_frame() {
+    let grid = build_grid(vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+
+    assert_eq!(Body.cells(&grid), Full.not(Head).cells(&grid));
+    assert_eq!(Full.not(Frame).cells(&grid), vec![(1, 1), (2, 1)]);
+    assert_eq!(Frame.cells(&grid).len(), 10);
+}
+
+#[test]
+fn segment() {
+    let grid = build_grid(vec![(1, 2, 3), (4, 5, 6)]);
+
+    assert_eq!(Segment(1.., 1..=1).cells(&grid), vec![(1, 1), (2, 1)]);
+    assert_eq!(Segment(..1, ..).cells(&grid), Head.cells(&grid));
+    assert!(Segment(1..1, ..).cells(&grid).is_empty());
+}
+
+#[test]
+fn every_row_and_column() {
+    let grid = build_grid(vec![(1, 2, 3), (4, 5, 6), (7, 8, 9), (10, 11, 12)]);
+
+    assert_eq!(
+        EveryRow(1.., 2).cells(&grid),
+        vec![(1, 0), (1, 1), (1, 2), (3, 0), (3, 1), (3, 2)]
+    );
+    assert_eq!(
+        EveryColumn(.., 2).not(Body).cells(&grid),
+        vec![(0, 0), (0, 2)]
+    );
+    assert_eq!(EveryRow(.., 0).cells(&grid), Full.cells(&grid));
+}
+
+#[test]
+fn ranges_with_excluded_start_and_overflow() {
+    let grid = build_grid(vec![(1, 2), (3, 4)]);
+
+    assert_eq!(
+        Row((Bound::Excluded(0), Bound::Unbounded)).cells(&grid),
+        Body.cells(&grid)
+    );
+    assert_eq!(
+        Column((Bound::Excluded(0), Bound::Included(1))).cells(&grid),
+        vec![(0, 1), (1, 1), (2, 1)]
+    );
+    assert_eq!(Row(1..100).cells(&grid), Body.cells(&grid));
+    assert!(Column(5..).cells(&grid).is_empty());
+    assert_eq!(
+        Cell(0, 0)
+            .and(Column((Bound::Excluded(2), Bound::Excluded(1))))
+            .cells(&grid),
+        vec![(0, 0)]
+    );
+}
+
+#[test]
+fn table_with_zebra_rows() {
+    let data = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Format(EveryRow(1.., 2), |s| format!("*{}", s)),
+        Format(Column::last().and(Row::last()).not(Head), |s| s
+            .to_uppercase()),
+    );
+
+    let expected = concat!(
+        " i32 |  &str  \n",
+        "-----+--------\n",
+        " *1  |  *ONE  \n",
+        "  2  |  TWO   \n",
+        " *3  | *THREE \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn intersect_and_xor() {
+    let grid = build_grid(vec![(1, 2, 3), (4, 5, 6)]);
+
+    assert_eq!(
+        Row(1..).intersect(Column(1..)).cells(&grid),
+        Segment(1.., 1..).cells(&grid)
+    );
+    assert_eq!(Head.intersect(Body).cells(&grid), vec![]);
+    assert_eq!(
+        Row(..2).xor(Column(..1)).cells(&grid),
+        vec![(0, 1), (0, 2), (1, 1), (1, 2), (2, 0)]
+    );
+    assert_eq!(Full.xor(Full).cells(&grid), vec![]);
+}
+
+#[test]
+fn cell_set() {
+    let mut set = CellSet::new(3, 70);
+    set.insert_segment(0..3, 60..70);
+    set.insert(1, 0);
+    set.insert(3, 0);
+
+    assert_eq!(set.len(), 31);
+    assert!(set.contains(1, 0));
+    assert!(set.contains(2, 69));
+    assert!(!set.contains(3, 0));
+    assert_eq!(set.iter().next(), Some((0, 60)));
+    assert_eq!(set.iter().nth(10), Some((1, 0)));
+    assert_eq!(set.iter().last(), Some((2, 69)));
+    assert_eq!(set.clone().into_iter().count(), 31);
+
+    // sets of different sizes are combined cell by cell
+    let mut other = CellSet::new(2, 2);
+    other.insert_segment(0..2, 0..2);
+    other.intersection(&set);
+    assert_eq!(other, vec![(1, 0)]);
+
+    set.difference(&other);
+    assert_eq!(set.len(), 30);
+
+    let mut set = CellSet::new(0, 0);
+    set.insert(0, 0);
+    assert!(set.is_empty());
+    assert_eq!(set.iter().count(), 0);
+}
+
+#[test]
+fn combinations_on_a_big_grid() {
+    let data = (0..50_000)
+        .map(|i| (i, i % 7, i % 2 == 0))
+        .collect::<Vec<_>>();
+    let grid = build_grid(data);
+
+    let cells = Column(..2)
+        .not(Head)
+        .not(RowsWhere(2, |s| s == "true"))
+        .intersect(EveryRow(.., 3))
+        .cells(&grid);
+
+    assert_eq!(cells.len(), 16_666);
+    assert_eq!(cells.iter().next(), Some((6, 0)));
+}
+
+#[test]
+fn rows_matching() {
+    let grid = build_grid(vec![
+        ("api", 200, true),
+        ("db", 500, false),
+        ("cache", 500, true),
+    ]);
+
+    let cells =
+        RowsMatching(|row| row.get_by_name("i32") == Some("500") && row.get(2) == Some("true"))
+            .cells(&grid);
+    assert_eq!(cells, vec![(3, 0), (3, 1), (3, 2)]);
+
+    let cells = RowsMatching(|row| row.index() % 2 == 0).cells(&grid);
+    assert_eq!(cells, Row(..1).and(Row(2..3)).cells(&grid));
+
+    let cells = RowsMatching(|row| row.cells().any(|s| s == "bool")).cells(&grid);
+    assert_eq!(cells, Head.cells(&grid));
+
+    let cells =
+        RowsMatching(|row| row.get(3).is_some() || row.get_by_name("u8").is_some()).cells(&grid);
+    assert!(cells.is_empty());
+}
+
+#[test]
+fn table_with_highlighted_rows() {
+    let data = vec![("api", "OK"), ("db", "FAILED"), ("cache", "OK")];
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Format(
+            RowsMatching(|row| row.index() > 0 && row.get(1) == Some("FAILED")),
+            |s| s.to_uppercase()
+        ),
+    );
+
+    let expected = concat!(
+        " &str  |  &str  \n",
+        "-------+--------\n",
+        "  api  |   OK   \n",
+        "  DB   | FAILED \n",
+        " cache |   OK   \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,144 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Records, Style, Tabled};
+
+#[derive(Tabled)]
+struct Commit {
+    hash: &'static str,
+    #[header(hidden)]
+    merged: bool,
+}
+
+#[test]
+fn records_sort_by_key() {
+    let data = vec![
+        ("dc3413c", 1622937600),
+        ("5e2c7d1", 1622851200),
+        ("a81b9f0", 1623024000),
+    ];
+    let expected = concat!(
+        "  &str   |    i32     \n",
+        "---------+------------\n",
+        " 5e2c7d1 | 1622851200 \n",
+        " dc3413c | 1622937600 \n",
+        " a81b9f0 | 1623024000 \n",
+    );
+
+    let records = Records::new(data).sort_by_key(|(_, created_at)| *created_at);
+    let table = table!(records, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn records_filter_by_hidden_field() {
+    let data = vec![
+        Commit {
+            hash: "dc3413c",
+            merged: true,
+        },
+        Commit {
+            hash: "a81b9f0",
+            merged: false,
+        },
+    ];
+    let expected = concat!("  hash   \n", "---------\n", " dc3413c \n",);
+
+    let records = Records::new(data).filter(|c| c.merged);
+    let table = table!(records, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn records_take_and_skip() {
+    let data = vec!["a", "b", "c", "d"];
+    let expected = concat!(" &str \n", "------\n", "  b   \n", "  c   \n",);
+
+    let records = Records::new(data).skip(1).take(2);
+    let table = table!(records, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn records_skip_all() {
+    let data = vec!["a", "b"];
+    let expected = concat!(" &str \n", "------\n",);
+
+    let records = Records::new(data).skip(3);
+    let table = table!(&records, Style::psql());
+
+    assert_eq!(table, expected);
+    assert!(records.is_empty());
+}
+
+#[test]
+fn records_dedup_by_adjacent_rows() {
+    let data = vec![("a", 1), ("b", 1), ("c", 2), ("d", 1)];
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  1  \n",
+        "  c   |  2  \n",
+        "  d   |  1  \n",
+    );
+
+    let records = Records::new(data).dedup_by(|a, b| a.1 == b.1);
+    let table = table!(records, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn records_reverse() {
+    let data = vec!["a", "b", "c"];
+    let expected = concat!(" &str \n", "------\n", "  c   \n", "  b   \n", "  a   \n",);
+
+    let records = Records::new(data).reverse();
+    let table = table!(records, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn records_empty() {
+    let data: Vec<&str> = Vec::new();
+    let expected = concat!(" &str \n", "------\n",);
+
+    let records = Records::new(data).sort_by_key(|s| *s).dedup_by_key(|s| *s);
+    let table = table!(&records, Style::psql());
+
+    assert_eq!(table, expected);
+    assert_eq!(records.len(), 0);
+}
+
+#[test]
+fn records_by_reference() {
+    let data = vec![("Maxim", 1), ("Alex", 2), ("Alex", 4), ("Maxim", 3)];
+    let expected = concat!(
+        " &str  | i32 \n",
+        "-------+-----\n",
+        " Alex  |  4  \n",
+        " Maxim |  3  \n",
+    );
+
+    let records = Records::new(data)
+        .sort_by(|a, b| b.1.cmp(&a.1))
+        .dedup_by_key(|c| c.0)
+        .take(2);
+    let table = table!(&records, Style::psql());
+
+    assert_eq!(table, expected);
+    assert_eq!(records.len(), 2);
+}
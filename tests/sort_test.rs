@@ -0,0 +1,247 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Cell, Column, Format, Order, Sort, Style};
+
+#[test]
+fn sort_natural_order() {
+    let data = vec!["sda10", "sda9", "sda1"];
+    let expected = concat!(
+        " &str  \n",
+        "-------\n",
+        " sda1  \n",
+        " sda9  \n",
+        " sda10 \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_descending_order() {
+    let data = vec![("sda10", 10), ("sda9", 9), ("sda1", 10)];
+    let expected = concat!(
+        " &str  | i32 \n",
+        "-------+-----\n",
+        " sda10 | 10  \n",
+        " sda9  |  9  \n",
+        " sda1  | 10  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..1), Order::Descending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_multiple_keys() {
+    let data = vec![("sda10", 10), ("sda9", 9), ("sda1", 10)];
+    let expected = concat!(
+        " &str  | i32 \n",
+        "-------+-----\n",
+        " sda9  |  9  \n",
+        " sda10 | 10  \n",
+        " sda1  | 10  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(1..2), Order::Ascending).then(Column(..1), Order::Descending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_custom_key() {
+    fn parse_size(s: &str) -> f64 {
+        let mut parts = s.split_whitespace();
+        let value = parts.next().unwrap().parse::<f64>().unwrap();
+        match parts.next() {
+            Some("GiB") => value * 1024.0,
+            _ => value,
+        }
+    }
+
+    let data = vec!["1.5 GiB", "512 MiB", "2 GiB"];
+    let expected = concat!(
+        "  &str   \n",
+        "---------\n",
+        " 512 MiB \n",
+        " 1.5 GiB \n",
+        "  2 GiB  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending).key(parse_size)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_with_indicators() {
+    let data = vec![("b", 1), ("a", 1)];
+    let expected = concat!(
+        " &str ▼ | i32 ▲ \n",
+        "--------+-------\n",
+        "   b    |   1   \n",
+        "   a    |   1   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(1..2), Order::Ascending)
+            .then(Column(..1), Order::Descending)
+            .indicators()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_after_formatting() {
+    let data = vec!["b", "c", "a"];
+    let expected = concat!(" &str \n", "------\n", "  ~a  \n", "  c   \n", "  b   \n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Format(Cell(3, 0), |s| format!("~{}", s)),
+        Sort::new(Column(..), Order::Descending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_keeps_order_of_equal_rows() {
+    let data = vec![("b", 1), ("a", 0), ("c", 1), ("d", 0)];
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  0  \n",
+        "  d   |  0  \n",
+        "  b   |  1  \n",
+        "  c   |  1  \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(1..), Order::Ascending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_empty_table() {
+    let data: Vec<&str> = Vec::new();
+    let expected = concat!(" &str ▲ \n", "--------\n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending).indicators()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_column_out_of_table() {
+    let data = vec!["b", "a"];
+    let expected = concat!(" &str \n", "------\n", "  b   \n", "  a   \n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(5..), Order::Ascending).indicators()
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_negative_numbers() {
+    let data = vec!["1", "-10", "3", "-5", "0"];
+    let expected = concat!(
+        " &str \n", "------\n", " -10  \n", "  -5  \n", "  0   \n", "  1   \n", "  3   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_fractional_numbers() {
+    let data = vec!["1.5", "3", "-0.5", "1.25", "-1.75", "1.05"];
+    let expected = concat!(
+        " &str  \n",
+        "-------\n",
+        " -1.75 \n",
+        " -0.5  \n",
+        " 1.05  \n",
+        " 1.25  \n",
+        "  1.5  \n",
+        "   3   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_hyphen_is_not_a_sign() {
+    let data = vec!["2021-06-10", "2021-06-09", "sda-10", "sda-9"];
+    let expected = concat!(
+        "    &str    \n",
+        "------------\n",
+        " 2021-06-09 \n",
+        " 2021-06-10 \n",
+        "   sda-9    \n",
+        "   sda-10   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Sort::new(Column(..), Order::Ascending)
+    );
+
+    assert_eq!(table, expected);
+}
@@ -10,7 +10,15 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
 use tabled::{table, Tabled};
 
 #[test]
@@ -431,6 +439,7 @@ mod default_types {
         assert_eq!(expected, table);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn table_enum() {
         #[derive(Tabled)]
@@ -457,24 +466,25 @@ mod default_types {
             Letters::Digit,
         ];
 
-        let expected = "+--------+-----------+-------+\n\
-                             | Vowels | Consonant | Digit |\n\
-                             +--------+-----------+-------+\n\
-                             |   +    |           |       |\n\
-                             +--------+-----------+-------+\n\
-                             |        |     +     |       |\n\
-                             +--------+-----------+-------+\n\
-                             |   +    |           |       |\n\
-                             +--------+-----------+-------+\n\
-                             |   +    |           |       |\n\
-                             +--------+-----------+-------+\n\
-                             |        |           |   +   |\n\
-                             +--------+-----------+-------+\n";
+        let expected = "+-----------+------+-----------+-------+\n\
+                             | character | lang | Consonant | Digit |\n\
+                             +-----------+------+-----------+-------+\n\
+                             |     a     |  0   |           |       |\n\
+                             +-----------+------+-----------+-------+\n\
+                             |           |      |     w     |       |\n\
+                             +-----------+------+-----------+-------+\n\
+                             |     b     |  1   |           |       |\n\
+                             +-----------+------+-----------+-------+\n\
+                             |     c     |  2   |           |       |\n\
+                             +-----------+------+-----------+-------+\n\
+                             |           |      |           |   +   |\n\
+                             +-----------+------+-----------+-------+\n";
 
         let table = table!(&data);
         assert_eq!(expected, table);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn table_enum_with_hidden_variant() {
         #[derive(Tabled)]
@@ -505,19 +515,19 @@ mod default_types {
             Letters::Digit,
         ];
 
-        let expected = "+--------+-----------+\n\
-                             | Vowels | Consonant |\n\
-                             +--------+-----------+\n\
-                             |   +    |           |\n\
-                             +--------+-----------+\n\
-                             |        |     +     |\n\
-                             +--------+-----------+\n\
-                             |   +    |           |\n\
-                             +--------+-----------+\n\
-                             |   +    |           |\n\
-                             +--------+-----------+\n\
-                             |        |           |\n\
-                             +--------+-----------+\n";
+        let expected = "+-----------+------+-----------+\n\
+                             | character | lang | Consonant |\n\
+                             +-----------+------+-----------+\n\
+                             |     a     |  0   |           |\n\
+                             +-----------+------+-----------+\n\
+                             |           |      |     w     |\n\
+                             +-----------+------+-----------+\n\
+                             |     b     |  1   |           |\n\
+                             +-----------+------+-----------+\n\
+                             |     c     |  2   |           |\n\
+                             +-----------+------+-----------+\n\
+                             |           |      |           |\n\
+                             +-----------+------+-----------+\n";
 
         let table = table!(&data);
         assert_eq!(expected, table);
@@ -660,4 +670,123 @@ mod default_types {
 
         assert_eq!(expected, table!(&languages));
     }
+
+    #[test]
+    fn table_string_vec() {
+        let data = vec![String::from("hello"), String::from("world")];
+        let expected = "+--------+\n\
+                             | String |\n\
+                             +--------+\n\
+                             | hello  |\n\
+                             +--------+\n\
+                             | world  |\n\
+                             +--------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_cow_vec() {
+        let data: Vec<Cow<str>> = vec![Cow::Borrowed("hello"), Cow::Owned(String::from("world"))];
+        let expected = "+----------+\n\
+                             | Cow<str> |\n\
+                             +----------+\n\
+                             |  hello   |\n\
+                             +----------+\n\
+                             |  world   |\n\
+                             +----------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_option_vec() {
+        let data = vec![Some((1, "one")), None];
+        let expected = "+-----+------+\n\
+                             | i32 | &str |\n\
+                             +-----+------+\n\
+                             |  1  | one  |\n\
+                             +-----+------+\n\
+                             |     |      |\n\
+                             +-----+------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_result_vec() {
+        let data: Vec<Result<u8, String>> = vec![Ok(1), Err(String::from("failed"))];
+        let expected = "+----+--------+\n\
+                             | u8 | String |\n\
+                             +----+--------+\n\
+                             | 1  |        |\n\
+                             +----+--------+\n\
+                             |    | failed |\n\
+                             +----+--------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_pointer_vec() {
+        let data = vec![(Box::new(1), Rc::new('a'), Arc::new(true))];
+        let expected = "+-----+------+------+\n\
+                             | i32 | char | bool |\n\
+                             +-----+------+------+\n\
+                             |  1  |  a   | true |\n\
+                             +-----+------+------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_array_vec() {
+        let data = vec![[1, 2, 3], [4, 5, 6]];
+        let expected = "+-----+-----+-----+\n\
+                             | i32 | i32 | i32 |\n\
+                             +-----+-----+-----+\n\
+                             |  1  |  2  |  3  |\n\
+                             +-----+-----+-----+\n\
+                             |  4  |  5  |  6  |\n\
+                             +-----+-----+-----+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_std_types_vec() {
+        let data = vec![(
+            Duration::from_millis(1500),
+            IpAddr::from([127, 0, 0, 1]),
+            SocketAddr::from(([10, 0, 0, 1], 8080)),
+            PathBuf::from("/tmp"),
+        )];
+        let expected = "+----------+-----------+---------------+---------+\n\
+                             | Duration |  IpAddr   |  SocketAddr   | PathBuf |\n\
+                             +----------+-----------+---------------+---------+\n\
+                             |   1.5s   | 127.0.0.1 | 10.0.0.1:8080 |  /tmp   |\n\
+                             +----------+-----------+---------------+---------+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_long_tuple() {
+        let data = vec![(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)];
+        let expected = "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+\n\
+                             | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 |\n\
+                             +-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+\n\
+                             |  0  |  1  |  2  |  3  |  4  |  5  |  6  |  7  |  8  |  9  | 10  | 11  |\n\
+                             +-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+\n";
+
+        let table = table!(&data);
+        assert_eq!(expected, table);
+    }
 }
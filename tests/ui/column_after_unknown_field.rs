@@ -0,0 +1,16 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+#[tabled(column(name = "sum", with = "Self::sum", after = "z"))]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn sum(&self) -> i32 {
+        self.x + self.y
+    }
+}
+
+fn main() {}
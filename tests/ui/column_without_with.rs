@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+#[tabled(column(name = "sum"))]
+struct Point {
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct Point {
+    #[field(display_with = 42)]
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+enum Shape {
+    Point {
+        #[header(inline)]
+        x: i32,
+    },
+}
+
+fn main() {}
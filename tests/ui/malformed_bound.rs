@@ -0,0 +1,7 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+#[tabled(bound = "T Display")]
+struct Wrapper<T>(T);
+
+fn main() {}
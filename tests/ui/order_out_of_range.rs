@@ -0,0 +1,10 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct Point {
+    #[tabled(order = 2)]
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct Point {
+    #[tabled(order = 0)]
+    x: i32,
+    #[tabled(order = 0)]
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+union Number {
+    int: i32,
+    float: f32,
+}
+
+fn main() {}
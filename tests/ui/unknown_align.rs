@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct Point {
+    #[field(align = "middle")]
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+#[tabled(rename_all = "Sponge case")]
+struct Point {
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tabled::Tabled;
+
+#[derive(Tabled)]
+struct Point {
+    #[header(hidden = "yes")]
+    x: i32,
+}
+
+fn main() {}